@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Local};
+
+use super::models::{parse_timestamp, Issue, Priority, Status};
+
+/// What happened to an issue between two loads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Created,
+    Closed,
+    Status {
+        from: Status,
+        to: Status,
+    },
+    Priority {
+        from: Priority,
+        to: Priority,
+    },
+    Labels {
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Created => write!(f, "created"),
+            Change::Closed => write!(f, "closed"),
+            Change::Status { from, to } => write!(f, "{} → {}", from, to),
+            Change::Priority { from, to } => write!(f, "{} → {}", from.label(), to.label()),
+            Change::Labels { added, removed } => {
+                let added = added.iter().map(|l| format!("+{}", l));
+                let removed = removed.iter().map(|l| format!("-{}", l));
+                write!(
+                    f,
+                    "labels {}",
+                    added.chain(removed).collect::<Vec<_>>().join(" ")
+                )
+            }
+        }
+    }
+}
+
+/// A change seen when the issues were reloaded.
+#[derive(Debug, Clone)]
+pub struct Activity {
+    /// When the reload noticed it, not when bd recorded it
+    pub at: DateTime<Local>,
+    pub issue_id: String,
+    pub title: String,
+    pub change: Change,
+}
+
+/// Changes from `old`, loaded from `since`, to `new`, loaded with the
+/// `label` filter, in `new`'s order. An issue new to the set was created
+/// only if bd dates it after `since`; otherwise it just gained the label.
+/// Issues that disappeared (deleted, or no longer matching the label) aren't
+/// reported.
+pub fn changes(
+    old: &[Issue],
+    new: &[Issue],
+    since: DateTime<Local>,
+    label: Option<&str>,
+    at: DateTime<Local>,
+) -> Vec<Activity> {
+    let old: HashMap<&str, &Issue> = old.iter().map(|i| (i.id.as_str(), i)).collect();
+    let mut activity = Vec::new();
+    for issue in new {
+        let mut push = |change| {
+            activity.push(Activity {
+                at,
+                issue_id: issue.id.clone(),
+                title: issue.title.clone(),
+                change,
+            })
+        };
+        let Some(before) = old.get(issue.id.as_str()) else {
+            if parse_timestamp(&issue.created_at).is_some_and(|created| created > since) {
+                push(Change::Created);
+            } else if let Some(label) = label {
+                push(Change::Labels {
+                    added: vec![label.to_string()],
+                    removed: Vec::new(),
+                });
+            }
+            continue;
+        };
+
+        if before.status != issue.status {
+            if issue.status == Status::Closed {
+                push(Change::Closed);
+            } else {
+                push(Change::Status {
+                    from: before.status,
+                    to: issue.status,
+                });
+            }
+        }
+        if before.priority != issue.priority {
+            push(Change::Priority {
+                from: before.priority,
+                to: issue.priority,
+            });
+        }
+        let added: Vec<String> = issue
+            .labels
+            .iter()
+            .filter(|l| !before.labels.contains(l))
+            .cloned()
+            .collect();
+        let removed: Vec<String> = before
+            .labels
+            .iter()
+            .filter(|l| !issue.labels.contains(l))
+            .cloned()
+            .collect();
+        if !added.is_empty() || !removed.is_empty() {
+            push(Change::Labels { added, removed });
+        }
+    }
+    activity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::models::IssueType;
+
+    fn issue(id: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            description: None,
+            status: Status::Open,
+            priority: Priority::new(2),
+            issue_type: IssueType::Task,
+            labels: vec!["ralph".to_string()],
+            assignee: None,
+            created_at: "2026-10-01T09:00:00Z".to_string(),
+            updated_at: "2026-10-01T09:00:00Z".to_string(),
+            closed_at: None,
+            dependency_count: 0,
+            dependent_count: 0,
+            dependencies: Vec::new(),
+            open_blocker_count: 0,
+            status_history: Vec::new(),
+        }
+    }
+
+    fn at(timestamp: &str) -> DateTime<Local> {
+        timestamp.parse().unwrap()
+    }
+
+    /// Changes between loads at 2026-10-10 and 2026-10-11, under a label.
+    fn changed(old: &[Issue], new: &[Issue]) -> Vec<(String, Change)> {
+        let since = at("2026-10-10T00:00:00Z");
+        changes(old, new, since, Some("ralph"), at("2026-10-11T00:00:00Z"))
+            .into_iter()
+            .map(|a| (a.issue_id, a.change))
+            .collect()
+    }
+
+    #[test]
+    fn unchanged_issues_log_nothing() {
+        let issues = [issue("bd-1"), issue("bd-2")];
+        assert!(changed(&issues, &issues).is_empty());
+    }
+
+    #[test]
+    fn issue_created_since_the_last_load_is_created() {
+        let mut new = issue("bd-2");
+        new.created_at = "2026-10-10T12:00:00Z".to_string();
+        let changes = changed(&[issue("bd-1")], &[issue("bd-1"), new]);
+        assert_eq!(changes, [("bd-2".to_string(), Change::Created)]);
+    }
+
+    #[test]
+    fn older_issue_new_to_the_set_gained_the_label() {
+        let changes = changed(&[issue("bd-1")], &[issue("bd-1"), issue("bd-2")]);
+        let gained = Change::Labels {
+            added: vec!["ralph".to_string()],
+            removed: Vec::new(),
+        };
+        assert_eq!(changes, [("bd-2".to_string(), gained)]);
+    }
+
+    #[test]
+    fn older_issue_new_to_an_unfiltered_set_logs_nothing() {
+        let since = at("2026-10-10T00:00:00Z");
+        let now = at("2026-10-11T00:00:00Z");
+        let old = [issue("bd-1")];
+        let new = [issue("bd-1"), issue("bd-2")];
+        assert!(changes(&old, &new, since, None, now).is_empty());
+    }
+
+    #[test]
+    fn issue_without_a_creation_date_isnt_created() {
+        let mut new = issue("bd-2");
+        new.created_at = String::new();
+        let changes = changes(&[], &[new], at("2026-10-10T00:00:00Z"), None, Local::now());
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn closing_is_its_own_change() {
+        let mut closed = issue("bd-1");
+        closed.status = Status::Closed;
+        assert_eq!(
+            changed(&[issue("bd-1")], &[closed]),
+            [("bd-1".to_string(), Change::Closed)]
+        );
+    }
+
+    #[test]
+    fn status_priority_and_label_changes_in_that_order() {
+        let mut after = issue("bd-1");
+        after.status = Status::InProgress;
+        after.priority = Priority::new(0);
+        after.labels = vec!["urgent".to_string()];
+        let changes: Vec<Change> = changed(&[issue("bd-1")], &[after])
+            .into_iter()
+            .map(|(_, change)| change)
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                Change::Status {
+                    from: Status::Open,
+                    to: Status::InProgress
+                },
+                Change::Priority {
+                    from: Priority::new(2),
+                    to: Priority::new(0)
+                },
+                Change::Labels {
+                    added: vec!["urgent".to_string()],
+                    removed: vec!["ralph".to_string()]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn disappeared_issues_arent_reported() {
+        assert!(changed(&[issue("bd-1"), issue("bd-2")], &[issue("bd-2")]).is_empty());
+    }
+
+    #[test]
+    fn changes_follow_the_new_order_and_say_when_they_were_seen() {
+        let (mut a, mut b) = (issue("bd-1"), issue("bd-2"));
+        a.status = Status::Closed;
+        b.status = Status::Closed;
+        let seen = at("2026-10-11T00:00:00Z");
+        let activity = changes(
+            &[issue("bd-1"), issue("bd-2")],
+            &[b, a],
+            at("2026-10-10T00:00:00Z"),
+            None,
+            seen,
+        );
+        let ids: Vec<&str> = activity.iter().map(|a| a.issue_id.as_str()).collect();
+        assert_eq!(ids, ["bd-2", "bd-1"]);
+        assert!(activity.iter().all(|a| a.at == seen));
+        assert_eq!(activity[0].title, "Issue bd-2");
+    }
+
+    #[test]
+    fn label_changes_display_with_signs() {
+        let change = Change::Labels {
+            added: vec!["a".to_string()],
+            removed: vec!["b".to_string()],
+        };
+        assert_eq!(change.to_string(), "labels +a -b");
+        let change = Change::Priority {
+            from: Priority::new(3),
+            to: Priority::new(1),
+        };
+        assert_eq!(change.to_string(), "P3 → P1");
+    }
+}
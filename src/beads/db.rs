@@ -1,8 +1,18 @@
+use std::collections::{HashMap, HashSet};
 use std::process::Command;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 
-use super::models::{BdIssue, Issue};
+use super::graph::IssueGraph;
+use super::models::{BdIssue, BdIssueDetails, Dependency, DependencyType, Issue, Status};
+
+/// Issues from one load.
+pub struct LoadedIssues {
+    pub issues: Vec<Issue>,
+    /// Why dependency edges are missing, if fetching them failed; the issues
+    /// still load without them
+    pub warning: Option<String>,
+}
 
 pub struct BeadsClient {
     project_dir: PathBuf,
@@ -40,13 +50,19 @@ impl BeadsClient {
         &self.beads_dir
     }
 
-    pub fn load_issues(&self, label_filter: Option<&str>) -> Result<Vec<Issue>> {
+    /// Loads issues with `bd list`, at most `limit` of them; None loads all.
+    pub fn load_issues(
+        &self,
+        label_filter: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<LoadedIssues> {
         let mut cmd = Command::new("bd");
+        // bd reads a limit of 0 as no limit
         cmd.arg("list")
             .arg("--json")
             .arg("--all")
             .arg("--limit")
-            .arg("500");
+            .arg(limit.unwrap_or(0).to_string());
         cmd.current_dir(&self.project_dir);
 
         if let Some(label) = label_filter {
@@ -64,12 +80,125 @@ impl BeadsClient {
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         if stdout.trim().is_empty() {
-            return Ok(Vec::new());
+            return Ok(LoadedIssues {
+                issues: Vec::new(),
+                warning: None,
+            });
         }
 
         let bd_issues: Vec<BdIssue> =
             serde_json::from_str(&stdout).context("Failed to parse bd list JSON")?;
 
-        Ok(bd_issues.into_iter().map(Issue::from).collect())
+        let mut issues: Vec<Issue> = bd_issues.into_iter().map(Issue::from).collect();
+        let warning = self
+            .fill_dependencies(&mut issues)
+            .err()
+            .map(|e| format!("Dependencies unavailable: {:#}", e));
+
+        let open_blockers: Vec<usize> = {
+            let graph = IssueGraph::new(&issues);
+            issues.iter().map(|i| graph.open_blockers(i).len()).collect()
+        };
+        for (issue, count) in issues.iter_mut().zip(open_blockers) {
+            issue.open_blocker_count = count;
+        }
+        Ok(LoadedIssues { issues, warning })
+    }
+
+    /// `bd list` only reports dependency counts on some versions, and never
+    /// the status of a dependency. Fetch with `bd show` the edges of issues
+    /// that have dependencies but no edges in the list output, and the status
+    /// of blockers outside the loaded set.
+    fn fill_dependencies(&self, issues: &mut [Issue]) -> Result<()> {
+        let loaded: HashSet<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+        let mut wanted: Vec<&str> = Vec::new();
+        for issue in issues.iter() {
+            if issue.dependency_count > 0 && issue.dependencies.is_empty() {
+                wanted.push(&issue.id);
+            }
+            wanted.extend(
+                issue
+                    .dependencies
+                    .iter()
+                    .filter(|d| d.dep_type == DependencyType::Blocks && d.status.is_none())
+                    .map(|d| d.depends_on_id.as_str())
+                    .filter(|id| !loaded.contains(id)),
+            );
+        }
+        wanted.sort_unstable();
+        wanted.dedup();
+        if wanted.is_empty() {
+            return Ok(());
+        }
+
+        let output = Command::new("bd")
+            .arg("show")
+            .arg("--json")
+            .args(&wanted)
+            .current_dir(&self.project_dir)
+            .output()
+            .context("Failed to run `bd`. Is it installed?")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd show failed: {}", stderr.trim());
+        }
+
+        // A single ID may come back as an object rather than an array
+        let value: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("Failed to parse bd show JSON")?;
+        let details: Vec<BdIssueDetails> = match value {
+            serde_json::Value::Array(_) => serde_json::from_value(value),
+            other => serde_json::from_value(other).map(|d| vec![d]),
+        }
+        .context("Failed to parse bd show JSON")?;
+
+        let positions: HashMap<String, usize> = issues
+            .iter()
+            .enumerate()
+            .map(|(pos, issue)| (issue.id.clone(), pos))
+            .collect();
+        let mut statuses = HashMap::new();
+        for detail in details {
+            statuses.insert(detail.id.clone(), Status::parse(&detail.status));
+            if let Some(&pos) = positions.get(&detail.id) {
+                if issues[pos].dependencies.is_empty() {
+                    issues[pos].dependencies =
+                        detail.dependencies.into_iter().map(Dependency::from).collect();
+                }
+            }
+        }
+        for dep in issues.iter_mut().flat_map(|i| i.dependencies.iter_mut()) {
+            if dep.status.is_none() {
+                dep.status = statuses.get(&dep.depends_on_id).copied();
+            }
+        }
+        Ok(())
+    }
+
+    /// Changes an issue's status. Closing goes through `bd close` so bd records
+    /// the close time.
+    pub fn update_status(&self, id: &str, status: Status) -> Result<()> {
+        let mut cmd = Command::new("bd");
+        if status == Status::Closed {
+            cmd.arg("close").arg(id);
+        } else {
+            cmd.arg("update")
+                .arg(id)
+                .arg("--status")
+                .arg(status.to_string());
+        }
+        cmd.current_dir(&self.project_dir);
+
+        let output = cmd
+            .output()
+            .context("Failed to run `bd`. Is it installed?")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bd update failed: {}", stderr.trim());
+        }
+
+        Ok(())
     }
 }
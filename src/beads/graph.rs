@@ -0,0 +1,537 @@
+use std::collections::{HashMap, HashSet};
+
+use super::models::{Dependency, DependencyType, Issue, IssueType, Status};
+
+/// Cap on blocking chains reported per issue, so dense graphs stay readable.
+const MAX_CHAINS: usize = 8;
+
+/// Relationships between the loaded issues. Edges pointing at issues outside
+/// the loaded set (e.g. filtered out by label) are ignored.
+pub struct IssueGraph<'a> {
+    issues: &'a [Issue],
+    by_id: HashMap<&'a str, &'a Issue>,
+    children: HashMap<&'a str, Vec<&'a str>>,
+    /// Issue -> issues it is blocked by (`blocks` dependencies).
+    blockers: HashMap<&'a str, Vec<&'a str>>,
+    /// Issue -> issues it blocks.
+    dependents: HashMap<&'a str, Vec<&'a str>>,
+}
+
+/// One visible row of the epic tree.
+#[derive(Debug, Clone)]
+pub struct TreeRow {
+    pub issue_id: String,
+    pub depth: usize,
+    pub has_children: bool,
+    pub expanded: bool,
+    /// (closed, total) over all descendants, for rows with children.
+    pub progress: Option<(usize, usize)>,
+}
+
+impl<'a> IssueGraph<'a> {
+    pub fn new(issues: &'a [Issue]) -> Self {
+        let by_id: HashMap<&str, &Issue> = issues.iter().map(|i| (i.id.as_str(), i)).collect();
+
+        let mut graph = IssueGraph {
+            issues,
+            by_id,
+            children: HashMap::new(),
+            blockers: HashMap::new(),
+            dependents: HashMap::new(),
+        };
+        for issue in issues {
+            let id = issue.id.as_str();
+            if let Some(parent) = graph.parent_of(issue) {
+                graph.children.entry(parent).or_default().push(id);
+            }
+            for dep in &issue.dependencies {
+                if dep.dep_type != DependencyType::Blocks {
+                    continue;
+                }
+                if let Some((&blocker, _)) = graph.by_id.get_key_value(dep.depends_on_id.as_str()) {
+                    graph.blockers.entry(id).or_default().push(blocker);
+                    graph.dependents.entry(blocker).or_default().push(id);
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn issue(&self, id: &str) -> Option<&'a Issue> {
+        self.by_id.get(id).copied()
+    }
+
+    /// The loaded parent of an issue: its parent-child dependency, or for
+    /// hierarchical IDs like `bd-a1b2.3`, the `bd-a1b2` prefix.
+    pub fn parent_of(&self, issue: &Issue) -> Option<&'a str> {
+        let candidate = issue
+            .parent_id()
+            .or_else(|| issue.id.rsplit_once('.').map(|(prefix, _)| prefix))?;
+        self.by_id.get_key_value(candidate).map(|(id, _)| *id)
+    }
+
+    pub fn children(&self, id: &str) -> &[&'a str] {
+        self.children.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Issues blocking this one.
+    pub fn blockers(&self, id: &str) -> &[&'a str] {
+        self.blockers.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Issues this one blocks.
+    pub fn dependents(&self, id: &str) -> &[&'a str] {
+        self.dependents.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Blockers of an issue that aren't closed yet, including ones outside the
+    /// loaded set. Blockers of unknown status aren't counted.
+    pub fn open_blockers(&self, issue: &'a Issue) -> Vec<&'a str> {
+        issue
+            .dependencies
+            .iter()
+            .filter(|d| d.dep_type == DependencyType::Blocks)
+            .filter(|d| self.status_of(d).is_some_and(|s| s != Status::Closed))
+            .map(|d| d.depends_on_id.as_str())
+            .collect()
+    }
+
+    /// Whether an issue may have open blockers that can't be seen: its edges
+    /// didn't load, or a blocker's status isn't known.
+    pub fn blockers_unknown(&self, issue: &Issue) -> bool {
+        (issue.dependency_count > 0 && issue.dependencies.is_empty())
+            || issue
+                .dependencies
+                .iter()
+                .any(|d| d.dep_type == DependencyType::Blocks && self.status_of(d).is_none())
+    }
+
+    /// Status of a dependency's target: the loaded issue's, else what bd
+    /// reported for it.
+    fn status_of(&self, dep: &Dependency) -> Option<Status> {
+        self.issue(&dep.depends_on_id)
+            .map(|i| i.status)
+            .or(dep.status)
+            .filter(|&s| s != Status::Unknown)
+    }
+
+    /// Mirrors `bd ready`: open, with no open blockers on the issue or any of
+    /// its ancestors. Blockers that can't be seen might be open, so an issue
+    /// with any isn't ready.
+    pub fn is_ready(&self, issue: &'a Issue) -> bool {
+        if issue.status != Status::Open {
+            return false;
+        }
+        let mut seen = HashSet::new();
+        let mut current = Some(issue);
+        while let Some(i) = current {
+            // Guard against parent cycles
+            if !seen.insert(i.id.as_str()) {
+                break;
+            }
+            if !self.open_blockers(i).is_empty() || self.blockers_unknown(i) {
+                return false;
+            }
+            current = self.parent_of(i).and_then(|p| self.issue(p));
+        }
+        true
+    }
+
+    /// Blocking chains from an issue down to its root causes: open blockers
+    /// that aren't blocked themselves. Each chain starts at a direct blocker
+    /// and ends at the root cause.
+    pub fn blocker_chains(&self, issue: &'a Issue) -> Vec<Vec<&'a str>> {
+        let mut chains = Vec::new();
+        let mut path = vec![issue.id.as_str()];
+        self.walk_blockers(issue.id.as_str(), &mut path, &mut chains);
+        chains
+    }
+
+    fn walk_blockers(&self, id: &'a str, path: &mut Vec<&'a str>, chains: &mut Vec<Vec<&'a str>>) {
+        if chains.len() >= MAX_CHAINS {
+            return;
+        }
+        // Blockers outside the loaded set are treated as root causes
+        let blockers = self.issue(id).map(|i| self.open_blockers(i)).unwrap_or_default();
+        if blockers.is_empty() || blockers.iter().all(|b| path.contains(b)) {
+            chains.push(path[1..].to_vec());
+            return;
+        }
+        for blocker in blockers {
+            if path.contains(&blocker) {
+                continue;
+            }
+            path.push(blocker);
+            self.walk_blockers(blocker, path, chains);
+            path.pop();
+        }
+    }
+
+    /// Issues involved in at least one blocking relationship, in load order.
+    pub fn connected_ids(&self) -> Vec<&'a str> {
+        self.issues
+            .iter()
+            .map(|i| i.id.as_str())
+            .filter(|id| self.blockers.contains_key(id) || self.dependents.contains_key(id))
+            .collect()
+    }
+
+    /// The focus issue plus everything within `depth` blocking hops upstream
+    /// and downstream of it.
+    pub fn neighborhood(&self, id: &str, depth: usize) -> Vec<&'a str> {
+        let Some((&focus, _)) = self.by_id.get_key_value(id) else {
+            return Vec::new();
+        };
+        let mut seen = vec![focus];
+        for upstream in [true, false] {
+            let mut frontier = vec![focus];
+            for _ in 0..depth {
+                let mut next = Vec::new();
+                for node in frontier {
+                    let neighbors = if upstream {
+                        self.blockers(node)
+                    } else {
+                        self.dependents(node)
+                    };
+                    for &n in neighbors {
+                        if !seen.contains(&n) {
+                            seen.push(n);
+                            next.push(n);
+                        }
+                    }
+                }
+                frontier = next;
+            }
+        }
+        seen
+    }
+
+    /// Every descendant of an issue, in depth-first order.
+    pub fn descendants(&self, id: &str) -> Vec<&'a Issue> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&str> = self.children(id).iter().rev().copied().collect();
+        let mut descendants = Vec::new();
+        while let Some(child) = stack.pop() {
+            if !seen.insert(child) {
+                continue;
+            }
+            if let Some(issue) = self.issue(child) {
+                descendants.push(issue);
+            }
+            stack.extend(self.children(child).iter().rev());
+        }
+        descendants
+    }
+
+    /// (closed, total) over every descendant of an issue.
+    pub fn descendant_progress(&self, id: &str) -> (usize, usize) {
+        let descendants = self.descendants(id);
+        let closed = descendants
+            .iter()
+            .filter(|i| i.status == Status::Closed)
+            .count();
+        (closed, descendants.len())
+    }
+
+    /// Flattens the epic hierarchy into rows, depth-first, skipping the
+    /// children of collapsed issues. Roots are epics without a loaded parent.
+    pub fn epic_tree(&self, collapsed: &HashSet<String>) -> Vec<TreeRow> {
+        let mut rows = Vec::new();
+        let mut seen = HashSet::new();
+        for issue in self.issues {
+            if issue.issue_type == IssueType::Epic && self.parent_of(issue).is_none() {
+                self.push_tree_rows(&issue.id, 0, collapsed, &mut seen, &mut rows);
+            }
+        }
+        rows
+    }
+
+    fn push_tree_rows(
+        &self,
+        id: &'a str,
+        depth: usize,
+        collapsed: &HashSet<String>,
+        seen: &mut HashSet<&'a str>,
+        rows: &mut Vec<TreeRow>,
+    ) {
+        if !seen.insert(id) {
+            return;
+        }
+        let children = self.children(id);
+        let has_children = !children.is_empty();
+        let expanded = has_children && !collapsed.contains(id);
+        rows.push(TreeRow {
+            issue_id: id.to_string(),
+            depth,
+            has_children,
+            expanded,
+            progress: has_children.then(|| self.descendant_progress(id)),
+        });
+        if expanded {
+            for &child in children {
+                self.push_tree_rows(child, depth + 1, collapsed, seen, rows);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::models::Priority;
+
+    fn issue(id: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            description: None,
+            status: Status::Open,
+            priority: Priority::new(2),
+            issue_type: IssueType::Task,
+            labels: Vec::new(),
+            assignee: None,
+            created_at: "2026-10-01T09:00:00Z".to_string(),
+            updated_at: "2026-10-01T09:00:00Z".to_string(),
+            closed_at: None,
+            dependency_count: 0,
+            dependent_count: 0,
+            dependencies: Vec::new(),
+            open_blocker_count: 0,
+            status_history: Vec::new(),
+        }
+    }
+
+    fn epic(id: &str) -> Issue {
+        Issue {
+            issue_type: IssueType::Epic,
+            ..issue(id)
+        }
+    }
+
+    fn closed(mut issue: Issue) -> Issue {
+        issue.status = Status::Closed;
+        issue
+    }
+
+    fn depends(mut issue: Issue, on: &str, dep_type: DependencyType) -> Issue {
+        issue.dependencies.push(Dependency {
+            depends_on_id: on.to_string(),
+            dep_type,
+            status: None,
+        });
+        issue.dependency_count += 1;
+        issue
+    }
+
+    fn child_of(issue: Issue, parent: &str) -> Issue {
+        depends(issue, parent, DependencyType::ParentChild)
+    }
+
+    fn blocked_by(issue: Issue, blocker: &str) -> Issue {
+        depends(issue, blocker, DependencyType::Blocks)
+    }
+
+    fn ids<'a>(issues: &[&'a Issue]) -> Vec<&'a str> {
+        issues.iter().map(|i| i.id.as_str()).collect()
+    }
+
+    fn rows(issues: &[Issue], collapsed: &[&str]) -> Vec<(String, usize)> {
+        let collapsed = collapsed.iter().map(|s| s.to_string()).collect();
+        IssueGraph::new(issues)
+            .epic_tree(&collapsed)
+            .into_iter()
+            .map(|row| (row.issue_id, row.depth))
+            .collect()
+    }
+
+    fn chains(issues: &[Issue], id: &str) -> Vec<Vec<String>> {
+        let graph = IssueGraph::new(issues);
+        graph
+            .blocker_chains(graph.issue(id).unwrap())
+            .into_iter()
+            .map(|chain| chain.into_iter().map(String::from).collect())
+            .collect()
+    }
+
+    #[test]
+    fn descendants_are_depth_first_in_load_order() {
+        let issues = [
+            epic("e"),
+            child_of(issue("a"), "e"),
+            child_of(issue("b"), "e"),
+            child_of(issue("a1"), "a"),
+            child_of(issue("a2"), "a"),
+        ];
+        let graph = IssueGraph::new(&issues);
+        assert_eq!(ids(&graph.descendants("e")), ["a", "a1", "a2", "b"]);
+        assert_eq!(ids(&graph.descendants("a")), ["a1", "a2"]);
+        assert!(graph.descendants("b").is_empty());
+        assert!(graph.descendants("missing").is_empty());
+    }
+
+    #[test]
+    fn hierarchical_ids_are_children_of_their_prefix() {
+        let issues = [
+            epic("bd-1"),
+            issue("bd-1.1"),
+            issue("bd-1.1.1"),
+            issue("bd-2.1"),
+        ];
+        let graph = IssueGraph::new(&issues);
+        assert_eq!(ids(&graph.descendants("bd-1")), ["bd-1.1", "bd-1.1.1"]);
+        // bd-2 isn't loaded, so bd-2.1 has no parent
+        assert_eq!(graph.parent_of(&issues[3]), None);
+    }
+
+    #[test]
+    fn descendants_stop_at_parent_cycles() {
+        let issues = [child_of(epic("a"), "b"), child_of(issue("b"), "a")];
+        let graph = IssueGraph::new(&issues);
+        assert_eq!(ids(&graph.descendants("a")), ["b", "a"]);
+    }
+
+    #[test]
+    fn descendant_progress_counts_closed_at_every_depth() {
+        let issues = [
+            epic("e"),
+            closed(child_of(issue("a"), "e")),
+            child_of(issue("b"), "e"),
+            closed(child_of(issue("b1"), "b")),
+        ];
+        let graph = IssueGraph::new(&issues);
+        assert_eq!(graph.descendant_progress("e"), (2, 3));
+        assert_eq!(graph.descendant_progress("a"), (0, 0));
+    }
+
+    #[test]
+    fn epic_tree_roots_are_epics_without_a_loaded_parent() {
+        let issues = [
+            epic("e1"),
+            issue("loose"),
+            child_of(issue("a"), "e1"),
+            child_of(epic("sub"), "e1"),
+            child_of(issue("s1"), "sub"),
+            epic("e2"),
+            child_of(epic("orphan"), "gone"),
+        ];
+        assert_eq!(
+            rows(&issues, &[]),
+            [
+                ("e1".to_string(), 0),
+                ("a".to_string(), 1),
+                ("sub".to_string(), 1),
+                ("s1".to_string(), 2),
+                ("e2".to_string(), 0),
+                ("orphan".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn collapsed_rows_hide_their_children() {
+        let issues = [
+            epic("e"),
+            child_of(epic("sub"), "e"),
+            child_of(issue("s1"), "sub"),
+        ];
+        assert_eq!(
+            rows(&issues, &["sub"]),
+            [("e".to_string(), 0), ("sub".to_string(), 1)]
+        );
+        assert_eq!(rows(&issues, &["e"]), [("e".to_string(), 0)]);
+
+        let collapsed = HashSet::from(["sub".to_string()]);
+        let tree = IssueGraph::new(&issues).epic_tree(&collapsed);
+        let sub = &tree[1];
+        assert!(sub.has_children && !sub.expanded);
+        assert_eq!(sub.progress, Some((0, 1)));
+    }
+
+    #[test]
+    fn leaf_rows_have_no_progress() {
+        let issues = [epic("e"), child_of(issue("a"), "e")];
+        let tree = IssueGraph::new(&issues).epic_tree(&HashSet::new());
+        assert_eq!(tree[0].progress, Some((0, 1)));
+        assert!(!tree[1].has_children && !tree[1].expanded);
+        assert_eq!(tree[1].progress, None);
+    }
+
+    #[test]
+    fn blocker_chains_follow_blockers_to_the_root_cause() {
+        let issues = [
+            blocked_by(issue("a"), "b"),
+            blocked_by(issue("b"), "c"),
+            issue("c"),
+        ];
+        assert_eq!(chains(&issues, "a"), [["b", "c"]]);
+    }
+
+    #[test]
+    fn blocker_chains_branch_per_blocker_and_skip_closed_ones() {
+        let issues = [
+            blocked_by(blocked_by(blocked_by(issue("a"), "b"), "c"), "done"),
+            blocked_by(issue("b"), "c"),
+            issue("c"),
+            closed(issue("done")),
+        ];
+        assert_eq!(chains(&issues, "a"), [vec!["b", "c"], vec!["c"]]);
+    }
+
+    #[test]
+    fn blockers_outside_the_loaded_set_are_root_causes() {
+        let mut a = blocked_by(issue("a"), "ext-1");
+        a.dependencies[0].status = Some(Status::Open);
+        let issues = [a];
+        assert_eq!(chains(&issues, "a"), [["ext-1"]]);
+    }
+
+    #[test]
+    fn blocker_chains_stop_at_cycles() {
+        let issues = [
+            blocked_by(issue("a"), "b"),
+            blocked_by(issue("b"), "c"),
+            blocked_by(issue("c"), "a"),
+        ];
+        assert_eq!(chains(&issues, "a"), [["b", "c"]]);
+    }
+
+    #[test]
+    fn blocker_chains_are_capped() {
+        let mut issues = vec![issue("a")];
+        for n in 0..MAX_CHAINS + 4 {
+            let id = format!("b{n}");
+            issues[0] = blocked_by(issues[0].clone(), &id);
+            issues.push(issue(&id));
+        }
+        let chains = chains(&issues, "a");
+        assert_eq!(chains.len(), MAX_CHAINS);
+        assert_eq!(chains[0], ["b0"]);
+    }
+
+    #[test]
+    fn ready_needs_every_blocker_known_and_closed() {
+        let issues = [
+            blocked_by(issue("a"), "done"),
+            closed(issue("done")),
+            blocked_by(issue("b"), "a"),
+            blocked_by(issue("c"), "ext-1"),
+        ];
+        let graph = IssueGraph::new(&issues);
+        assert!(graph.is_ready(&issues[0]));
+        assert!(!graph.is_ready(&issues[1]));
+        assert!(!graph.is_ready(&issues[2]));
+        // ext-1's status is unknown
+        assert!(!graph.is_ready(&issues[3]));
+    }
+
+    #[test]
+    fn ready_checks_ancestors_blockers() {
+        let issues = [
+            blocked_by(epic("e"), "x"),
+            issue("x"),
+            child_of(issue("a"), "e"),
+        ];
+        let graph = IssueGraph::new(&issues);
+        assert!(!graph.is_ready(&issues[2]));
+    }
+}
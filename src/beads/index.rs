@@ -0,0 +1,236 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use memchr::memmem::Finder;
+use regex::Regex;
+
+use super::models::Issue;
+use super::query::{Query, TextMatcher};
+
+/// Heads skim aligns a fuzzy pattern against optimally; past this many it
+/// scores greedily.
+const MAX_ALIGNED_HEADS: usize = 2_000;
+
+/// Lookups over the loaded issues, built once per reload so searching doesn't
+/// redo per-issue work on every keystroke.
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    positions: HashMap<String, usize>,
+    entries: Vec<Entry>,
+}
+
+/// One issue's searchable text, fields separated by newlines so a typed
+/// pattern can't match across two of them.
+#[derive(Debug, Clone)]
+struct Entry {
+    /// ID, title, assignee, labels, then the description
+    text: String,
+    /// `text`, lowercased
+    lowered: String,
+    /// Where the title ends in `text` and in `lowered`
+    title_end: (usize, usize),
+    /// Where the labels end in `text` and in `lowered`: the short fields
+    /// fuzzy search ranks by
+    head_end: (usize, usize),
+    /// Which ASCII letters and digits appear in `lowered`
+    chars: u64,
+    /// Place in priority-then-ID order, the tiebreak between equal scores
+    rank: usize,
+}
+
+/// How well an issue matches the bare words: which fields matched, then how
+/// well within them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Score {
+    tier: u8,
+    within: i64,
+}
+
+impl SearchIndex {
+    pub fn new(issues: &[Issue]) -> Self {
+        let positions = issues
+            .iter()
+            .enumerate()
+            .map(|(pos, issue)| (issue.id.clone(), pos))
+            .collect();
+        let mut by_rank: Vec<usize> = (0..issues.len()).collect();
+        by_rank.sort_by_key(|&pos| (issues[pos].priority.0, &issues[pos].id));
+        let mut ranks = vec![0; issues.len()];
+        for (rank, pos) in by_rank.into_iter().enumerate() {
+            ranks[pos] = rank;
+        }
+        let entries = issues
+            .iter()
+            .zip(ranks)
+            .map(|(issue, rank)| Entry::new(issue, rank))
+            .collect();
+        SearchIndex { positions, entries }
+    }
+
+    /// Position of the issue with this ID in the indexed slice.
+    pub fn position(&self, id: &str) -> Option<usize> {
+        self.positions.get(id).copied()
+    }
+
+    /// Positions of the issues passing `query`, best match for the bare words
+    /// first, ties by priority then ID. Without a matcher they stay in load
+    /// order. `issues` must be the slice the index was built from.
+    pub fn search(
+        &self,
+        issues: &[Issue],
+        query: &Query,
+        matcher: Option<&TextMatcher>,
+    ) -> Vec<usize> {
+        let Some(matcher) = matcher else {
+            return (0..issues.len())
+                .filter(|&pos| query.matches(&issues[pos]))
+                .collect();
+        };
+        let required = required_chars(matcher);
+        let candidates = self.entries.iter().enumerate().filter(|&(pos, entry)| {
+            entry.chars & required == required && query.matches(&issues[pos])
+        });
+        let mut results: Vec<(Score, usize, usize)> = match matcher {
+            TextMatcher::Fuzzy(skim, pattern) => fuzzy_scores(candidates, skim, pattern),
+            TextMatcher::Exact(pattern) => {
+                let finder = Finder::new(pattern);
+                candidates
+                    .filter_map(|(pos, entry)| {
+                        let at = finder.find(entry.lowered.as_bytes())?;
+                        Some((Score::field(at < entry.title_end.1), entry.rank, pos))
+                    })
+                    .collect()
+            }
+            TextMatcher::CaseSensitive(pattern) => {
+                let finder = Finder::new(pattern);
+                candidates
+                    .filter_map(|(pos, entry)| {
+                        let at = finder.find(entry.text.as_bytes())?;
+                        Some((Score::field(at < entry.title_end.0), entry.rank, pos))
+                    })
+                    .collect()
+            }
+            TextMatcher::Regex(regex) => candidates
+                .filter_map(|(pos, entry)| {
+                    Some((regex_score(&issues[pos], regex)?, entry.rank, pos))
+                })
+                .collect(),
+        };
+        results.sort_unstable_by_key(|&(score, rank, _)| (Reverse(score), rank));
+        results.into_iter().map(|(_, _, pos)| pos).collect()
+    }
+}
+
+impl Entry {
+    fn new(issue: &Issue, rank: usize) -> Self {
+        let title = format!("{}\n{}", issue.id, issue.title);
+        let head = format!(
+            "{}\n{}\n{}",
+            title,
+            issue.assignee.as_deref().unwrap_or(""),
+            issue.labels.join("\n")
+        );
+        let text = format!("{}\n{}", head, issue.description.as_deref().unwrap_or(""));
+        let lowered = text.to_lowercase();
+        Entry {
+            title_end: (title.len(), title.to_lowercase().len()),
+            head_end: (head.len(), head.to_lowercase().len()),
+            chars: char_mask(&lowered),
+            rank,
+            text,
+            lowered,
+        }
+    }
+}
+
+impl Score {
+    /// A non-fuzzy match, ranked by whether it was in the ID or title.
+    fn field(in_title: bool) -> Self {
+        Score {
+            tier: if in_title { 2 } else { 1 },
+            within: 0,
+        }
+    }
+}
+
+/// Fuzzy matches in the ID, title, assignee or labels rank by skim's score,
+/// above ones that need the description. Skimming every description would
+/// blow the frame budget, so those only have to contain the pattern's chars
+/// in order.
+fn fuzzy_scores<'a>(
+    candidates: impl Iterator<Item = (usize, &'a Entry)>,
+    skim: &SkimMatcherV2,
+    pattern: &str,
+) -> Vec<(Score, usize, usize)> {
+    let mut in_head = Vec::new();
+    let mut results = Vec::new();
+    for (pos, entry) in candidates {
+        if is_subsequence(pattern, &entry.lowered[..entry.head_end.1]) {
+            in_head.push((pos, entry));
+        } else if is_subsequence(pattern, &entry.lowered) {
+            results.push((Score::default(), entry.rank, pos));
+        }
+    }
+    // Short patterns match most heads; skim's linear greedy scoring (what it
+    // falls back to past an element limit) keeps those within budget
+    let greedy = SkimMatcherV2::default().element_limit(1);
+    let skim = if in_head.len() > MAX_ALIGNED_HEADS {
+        &greedy
+    } else {
+        skim
+    };
+    results.extend(in_head.into_iter().map(|(pos, entry)| {
+        let score = skim
+            .fuzzy_match(&entry.text[..entry.head_end.0], pattern)
+            .map_or(Score::default(), |within| Score { tier: 1, within });
+        (score, entry.rank, pos)
+    }));
+    results
+}
+
+/// A regex match, each field on its own so anchors mean what they do there.
+fn regex_score(issue: &Issue, regex: &Regex) -> Option<Score> {
+    if regex.is_match(&issue.id) || regex.is_match(&issue.title) {
+        return Some(Score::field(true));
+    }
+    let elsewhere = issue
+        .description
+        .iter()
+        .chain(issue.assignee.iter())
+        .chain(issue.labels.iter())
+        .any(|text| regex.is_match(text));
+    elsewhere.then(|| Score::field(false))
+}
+
+/// Whether `pattern`'s chars all appear in `text`, in order.
+fn is_subsequence(pattern: &str, text: &str) -> bool {
+    let mut rest = text;
+    pattern.chars().all(|c| match rest.find(c) {
+        Some(pos) => {
+            rest = &rest[pos + c.len_utf8()..];
+            true
+        }
+        None => false,
+    })
+}
+
+/// Letters and digits a text must contain for the matcher to match it; none
+/// are known for a regex.
+fn required_chars(matcher: &TextMatcher) -> u64 {
+    match matcher {
+        TextMatcher::Fuzzy(_, pattern) | TextMatcher::Exact(pattern) => char_mask(pattern),
+        TextMatcher::CaseSensitive(pattern) => char_mask(&pattern.to_lowercase()),
+        TextMatcher::Regex(_) => 0,
+    }
+}
+
+/// One bit per lowercase ASCII letter and digit in `text`.
+fn char_mask(text: &str) -> u64 {
+    text.bytes().fold(0, |mask, b| match b {
+        b'a'..=b'z' => mask | 1 << (b - b'a'),
+        b'0'..=b'9' => mask | 1 << (26 + b - b'0'),
+        _ => mask,
+    })
+}
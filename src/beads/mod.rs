@@ -1,5 +1,11 @@
+pub mod activity;
 pub mod db;
+pub mod graph;
+pub mod index;
 pub mod models;
+pub mod query;
+pub mod stats;
 
 pub use db::BeadsClient;
+pub use graph::IssueGraph;
 pub use models::{Issue, Status};
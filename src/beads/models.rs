@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -30,6 +31,52 @@ pub struct BdIssue {
     pub created_at: String,
     #[serde(default)]
     pub updated_at: String,
+    #[serde(default)]
+    pub closed_at: String,
+    #[serde(default)]
+    pub dependencies: Vec<BdDependency>,
+    /// Audit trail, only emitted by bd versions that export events.
+    #[serde(default)]
+    pub events: Vec<BdEvent>,
+}
+
+/// An entry in an issue's audit trail. Values are either a bare status or a
+/// JSON object with a `status` field, depending on the bd version.
+#[derive(Debug, Deserialize)]
+pub struct BdEvent {
+    #[serde(default)]
+    pub event_type: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub old_value: Option<String>,
+    #[serde(default)]
+    pub new_value: Option<String>,
+}
+
+/// A dependency edge as emitted by bd. `bd list`/`bd export` use
+/// `depends_on_id` + `type`; `bd show` embeds the target issue with its `id`
+/// and a `dependency_type`.
+#[derive(Debug, Deserialize)]
+pub struct BdDependency {
+    #[serde(alias = "id")]
+    pub depends_on_id: String,
+    #[serde(default, rename = "type", alias = "dependency_type")]
+    pub dep_type: String,
+    /// Only present in `bd show` output.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// The subset of `bd show --json` output needed to fill in dependency edges
+/// and the status of blockers outside the loaded set.
+#[derive(Debug, Deserialize)]
+pub struct BdIssueDetails {
+    pub id: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub dependencies: Vec<BdDependency>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,8 +91,95 @@ pub struct Issue {
     pub assignee: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    pub closed_at: Option<String>,
     pub dependency_count: u32,
     pub dependent_count: u32,
+    /// Issues this one depends on.
+    pub dependencies: Vec<Dependency>,
+    /// Blockers that aren't closed yet, resolved against the other issues
+    /// after loading.
+    pub open_blocker_count: usize,
+    /// Status transitions from bd's event history, oldest first. Empty when
+    /// bd doesn't export events.
+    pub status_history: Vec<StatusChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub at: String,
+    pub from: Option<Status>,
+    pub to: Status,
+}
+
+impl StatusChange {
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.at)
+    }
+
+    /// Status transitions only; events without a parseable time are dropped.
+    fn from_event(event: &BdEvent) -> Option<Self> {
+        parse_timestamp(&event.created_at)?;
+        let from = event.old_value.as_deref().and_then(event_status);
+        let to = match event.event_type.as_str() {
+            "status_changed" => event.new_value.as_deref().and_then(event_status)?,
+            "closed" => Status::Closed,
+            "reopened" => Status::Open,
+            _ => return None,
+        };
+        Some(StatusChange {
+            at: event.created_at.clone(),
+            from,
+            to,
+        })
+    }
+}
+
+fn event_status(value: &str) -> Option<Status> {
+    let status = match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Object(fields)) => Status::parse(fields.get("status")?.as_str()?),
+        _ => Status::parse(value.trim_matches('"')),
+    };
+    (status != Status::Unknown).then_some(status)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DependencyType {
+    Blocks,
+    ParentChild,
+    Related,
+    DiscoveredFrom,
+    Other,
+}
+
+impl DependencyType {
+    pub fn parse(s: &str) -> DependencyType {
+        match s {
+            "blocks" | "" => DependencyType::Blocks,
+            "parent-child" => DependencyType::ParentChild,
+            "related" => DependencyType::Related,
+            "discovered-from" => DependencyType::DiscoveredFrom,
+            _ => DependencyType::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dependency {
+    pub depends_on_id: String,
+    pub dep_type: DependencyType,
+    /// Status of the target issue, when bd reported it.
+    pub status: Option<Status>,
+}
+
+impl From<BdDependency> for Dependency {
+    fn from(bd: BdDependency) -> Self {
+        Dependency {
+            depends_on_id: bd.depends_on_id,
+            dep_type: DependencyType::parse(&bd.dep_type),
+            status: bd.status.as_deref().map(Status::parse),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -73,7 +207,7 @@ impl fmt::Display for Status {
 }
 
 impl Status {
-    pub fn from_str(s: &str) -> Status {
+    pub fn parse(s: &str) -> Status {
         match s {
             "open" => Status::Open,
             "in_progress" => Status::InProgress,
@@ -135,9 +269,29 @@ impl fmt::Display for IssueType {
     }
 }
 
+impl IssueType {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            IssueType::Task => "📝",
+            IssueType::Bug => "🐛",
+            IssueType::Feature => "✨",
+            IssueType::Epic => "🎯",
+            IssueType::Chore => "🔧",
+            IssueType::Other => "📌",
+        }
+    }
+}
+
+/// Parses a bd timestamp (RFC 3339) into UTC.
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
 impl From<BdIssue> for Issue {
     fn from(bd: BdIssue) -> Self {
-        let status = Status::from_str(&bd.status);
+        let status = Status::parse(&bd.status);
         let priority = Priority::new(bd.priority);
         let issue_type = match bd.issue_type.as_str() {
             "bug" => IssueType::Bug,
@@ -157,6 +311,17 @@ impl From<BdIssue> for Issue {
         } else {
             Some(bd.description)
         };
+        let closed_at = if bd.closed_at.is_empty() {
+            None
+        } else {
+            Some(bd.closed_at)
+        };
+        let mut status_history: Vec<StatusChange> = bd
+            .events
+            .iter()
+            .filter_map(StatusChange::from_event)
+            .collect();
+        status_history.sort_by_key(StatusChange::time);
 
         Issue {
             id: bd.id,
@@ -169,14 +334,78 @@ impl From<BdIssue> for Issue {
             assignee,
             created_at: bd.created_at,
             updated_at: bd.updated_at,
+            closed_at,
             dependency_count: bd.dependency_count,
             dependent_count: bd.dependent_count,
+            dependencies: bd.dependencies.into_iter().map(Dependency::from).collect(),
+            open_blocker_count: 0,
+            status_history,
         }
     }
 }
 
 impl Issue {
+    /// Blocked by at least one open issue, or explicitly marked blocked.
     pub fn is_blocked(&self) -> bool {
-        self.dependency_count > 0
+        self.status != Status::Closed
+            && (self.open_blocker_count > 0 || self.status == Status::Blocked)
+    }
+
+    pub fn created_time(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.created_at)
+    }
+
+    /// When the issue was closed. Falls back to the last update for closed
+    /// issues from bd versions that don't report `closed_at`.
+    pub fn closed_time(&self) -> Option<DateTime<Utc>> {
+        if self.status != Status::Closed {
+            return None;
+        }
+        self.closed_at
+            .as_deref()
+            .and_then(parse_timestamp)
+            .or_else(|| parse_timestamp(&self.updated_at))
+    }
+
+    /// Best guess at the issue's status at a point in time: replayed from the
+    /// event history when bd exported one, otherwise inferred from the
+    /// created/updated/closed timestamps. None before the issue existed.
+    pub fn status_at(&self, at: DateTime<Utc>) -> Option<Status> {
+        if self.created_time()? > at {
+            return None;
+        }
+        if let Some(first) = self.status_history.first() {
+            let status = match self
+                .status_history
+                .iter()
+                .rev()
+                .find(|c| c.time().is_some_and(|t| t <= at))
+            {
+                Some(change) => change.to,
+                None => first.from.unwrap_or(Status::Open),
+            };
+            return Some(status);
+        }
+        if self.closed_time().is_some_and(|closed| closed <= at) {
+            return Some(Status::Closed);
+        }
+        let status = match self.status {
+            // Closed later; whether it was in progress first is unknown
+            Status::Closed => Status::Open,
+            // Assume work started at the last update
+            Status::InProgress if parse_timestamp(&self.updated_at).is_some_and(|u| u > at) => {
+                Status::Open
+            }
+            status => status,
+        };
+        Some(status)
+    }
+
+    /// The parent epic, from a parent-child dependency.
+    pub fn parent_id(&self) -> Option<&str> {
+        self.dependencies
+            .iter()
+            .find(|d| d.dep_type == DependencyType::ParentChild)
+            .map(|d| d.depends_on_id.as_str())
     }
 }
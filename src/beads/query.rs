@@ -0,0 +1,928 @@
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::models::{parse_timestamp, Issue, IssueType, Status};
+
+/// A parsed search query, e.g.
+/// `status:open label:backend -label:wontfix priority:<=1 updated:<7d "exact text" fuzzy`.
+/// Every term must hold for an issue to match.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    pub terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Term {
+    /// Written with a leading `-`: the filter must not hold
+    pub negated: bool,
+    pub filter: Filter,
+}
+
+#[derive(Debug, Clone)]
+pub enum Filter {
+    Status(Status),
+    /// Lowercased
+    Label(String),
+    Priority(Cmp, u8),
+    /// Lowercased
+    Assignee(String),
+    Type(IssueType),
+    Date {
+        field: DateField,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    },
+    /// Quoted text must appear (ignoring case); bare words are matched by
+    /// the caller in its search mode
+    Text {
+        text: String,
+        quoted: bool,
+    },
+    /// An excluded bare word, in the query's search mode. Fuzzy mode matches
+    /// it as exact text, as nearly every issue would fuzzy-match it
+    Word(TextMatcher),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmp {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateField {
+    Created,
+    Updated,
+}
+
+/// How a query's bare words are matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    #[default]
+    Fuzzy,
+    /// Substring, ignoring case
+    Exact,
+    /// Substring, matching case
+    CaseSensitive,
+    Regex,
+}
+
+impl SearchMode {
+    pub fn next(self) -> Self {
+        match self {
+            SearchMode::Fuzzy => SearchMode::Exact,
+            SearchMode::Exact => SearchMode::CaseSensitive,
+            SearchMode::CaseSensitive => SearchMode::Regex,
+            SearchMode::Regex => SearchMode::Fuzzy,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchMode::Fuzzy => "fuzzy",
+            SearchMode::Exact => "exact",
+            SearchMode::CaseSensitive => "case",
+            SearchMode::Regex => "regex",
+        }
+    }
+}
+
+/// Matches a query's bare words in a given mode.
+pub enum TextMatcher {
+    Fuzzy(Box<SkimMatcherV2>, String),
+    Exact(String),
+    CaseSensitive(String),
+    Regex(Regex),
+}
+
+impl TextMatcher {
+    /// Fails only for an invalid regex, with the reason.
+    pub fn new(mode: SearchMode, text: &str) -> Result<Self, String> {
+        Ok(match mode {
+            // Lowercased, or skim would match case once the text has a capital
+            SearchMode::Fuzzy => TextMatcher::Fuzzy(Box::default(), text.to_lowercase()),
+            SearchMode::Exact => TextMatcher::Exact(text.to_lowercase()),
+            SearchMode::CaseSensitive => TextMatcher::CaseSensitive(text.to_string()),
+            SearchMode::Regex => TextMatcher::Regex(Regex::new(text).map_err(|e| {
+                // The last line of the error says what's wrong; the rest
+                // repeats the pattern
+                let reason = e.to_string();
+                let reason = reason.lines().last().unwrap_or_default();
+                format!("Invalid regex: {}", reason.trim_start_matches("error: "))
+            })?),
+        })
+    }
+
+    /// Matcher for an excluded word: anything but fuzzy, which would match
+    /// nearly everything.
+    fn exclusion(mode: SearchMode, text: &str) -> Result<Self, String> {
+        match mode {
+            SearchMode::Fuzzy => TextMatcher::new(SearchMode::Exact, text),
+            mode => TextMatcher::new(mode, text),
+        }
+    }
+
+    /// Match score within one string, higher is better.
+    pub fn score(&self, text: &str) -> Option<i64> {
+        let found = match self {
+            TextMatcher::Fuzzy(matcher, pattern) => return matcher.fuzzy_match(text, pattern),
+            TextMatcher::Exact(pattern) => text.to_lowercase().contains(pattern.as_str()),
+            TextMatcher::CaseSensitive(pattern) => text.contains(pattern.as_str()),
+            TextMatcher::Regex(regex) => regex.find(text).is_some_and(|m| !m.is_empty()),
+        };
+        found.then_some(1)
+    }
+
+    /// Char indices of `text` that match; empty when it doesn't.
+    pub fn indices(&self, text: &str) -> Vec<usize> {
+        let byte_range = match self {
+            TextMatcher::Fuzzy(matcher, pattern) => {
+                return matcher
+                    .fuzzy_indices(text, pattern)
+                    .map(|(_, indices)| indices)
+                    .unwrap_or_default();
+            }
+            TextMatcher::Exact(pattern) => {
+                // Lowercasing can change byte lengths, so map back via chars
+                let lower = text.to_lowercase();
+                let Some(pos) = lower.find(pattern.as_str()) else {
+                    return Vec::new();
+                };
+                let start = lower[..pos].chars().count();
+                return (start..start + pattern.chars().count()).collect();
+            }
+            TextMatcher::CaseSensitive(pattern) => text
+                .find(pattern.as_str())
+                .map(|pos| pos..pos + pattern.len()),
+            TextMatcher::Regex(regex) => regex
+                .find(text)
+                .filter(|m| !m.is_empty())
+                .map(|m| m.range()),
+        };
+        let Some(range) = byte_range else {
+            return Vec::new();
+        };
+        let start = text[..range.start].chars().count();
+        (start..start + text[range].chars().count()).collect()
+    }
+}
+
+// By hand, as skim's matcher is neither Clone nor Debug; it keeps nothing
+// worth copying
+impl Clone for TextMatcher {
+    fn clone(&self) -> Self {
+        match self {
+            TextMatcher::Fuzzy(_, pattern) => TextMatcher::Fuzzy(Box::default(), pattern.clone()),
+            TextMatcher::Exact(pattern) => TextMatcher::Exact(pattern.clone()),
+            TextMatcher::CaseSensitive(pattern) => TextMatcher::CaseSensitive(pattern.clone()),
+            TextMatcher::Regex(regex) => TextMatcher::Regex(regex.clone()),
+        }
+    }
+}
+
+impl fmt::Debug for TextMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextMatcher::Fuzzy(_, pattern) => f.debug_tuple("Fuzzy").field(pattern).finish(),
+            TextMatcher::Exact(pattern) => f.debug_tuple("Exact").field(pattern).finish(),
+            TextMatcher::CaseSensitive(pattern) => {
+                f.debug_tuple("CaseSensitive").field(pattern).finish()
+            }
+            TextMatcher::Regex(regex) => f.debug_tuple("Regex").field(regex).finish(),
+        }
+    }
+}
+
+/// A syntax error, with the char range of the offending term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Query {
+    /// Parses a query whose bare words are matched in `mode`. Relative dates
+    /// (`updated:<7d`) are taken from now.
+    ///
+    /// In regex mode the bare words are one pattern, taken as typed from the
+    /// first of them to the last, so its spaces, quotes and colons are kept.
+    /// Filters and exclusions only count before or after it.
+    pub fn parse(input: &str, mode: SearchMode) -> Result<Query, QueryError> {
+        let now = Utc::now();
+        let tokens = tokenize(input, mode)?;
+        if mode == SearchMode::Regex {
+            return parse_regex_query(input, &tokens, now);
+        }
+        let terms = tokens
+            .iter()
+            .map(|token| parse_term(token, mode, now))
+            .collect::<Result<_, _>>()?;
+        Ok(Query { terms })
+    }
+
+    /// The bare words, joined, for the caller to match and rank by.
+    /// Excluded bare words are checked by `matches` instead.
+    pub fn bare_text(&self) -> Option<String> {
+        let words: Vec<&str> = self
+            .terms
+            .iter()
+            .filter_map(|term| match term.filter {
+                Filter::Text {
+                    ref text,
+                    quoted: false,
+                } if !term.negated => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+
+    /// Matchers for the quoted phrases an issue must contain, for highlighting.
+    pub fn phrase_matchers(&self) -> Vec<TextMatcher> {
+        self.terms
+            .iter()
+            .filter_map(|term| match term.filter {
+                Filter::Text {
+                    ref text,
+                    quoted: true,
+                } if !term.negated => Some(TextMatcher::Exact(text.to_lowercase())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether the issue passes every term except the bare words.
+    pub fn matches(&self, issue: &Issue) -> bool {
+        self.terms.iter().all(|term| match term.filter {
+            Filter::Text { quoted: false, .. } if !term.negated => true,
+            ref filter => filter.matches(issue) != term.negated,
+        })
+    }
+}
+
+impl Filter {
+    fn matches(&self, issue: &Issue) -> bool {
+        match self {
+            Filter::Status(status) => issue.status == *status,
+            Filter::Label(label) => issue.labels.iter().any(|l| l.to_lowercase() == *label),
+            Filter::Priority(cmp, value) => {
+                let p = issue.priority.0;
+                match cmp {
+                    Cmp::Lt => p < *value,
+                    Cmp::Le => p <= *value,
+                    Cmp::Eq => p == *value,
+                    Cmp::Ge => p >= *value,
+                    Cmp::Gt => p > *value,
+                }
+            }
+            Filter::Assignee(assignee) => issue
+                .assignee
+                .as_ref()
+                .is_some_and(|a| a.to_lowercase() == *assignee),
+            Filter::Type(issue_type) => issue.issue_type == *issue_type,
+            Filter::Date {
+                field,
+                after,
+                before,
+            } => {
+                let at = match field {
+                    DateField::Created => &issue.created_at,
+                    DateField::Updated => &issue.updated_at,
+                };
+                let Some(at) = parse_timestamp(at) else {
+                    return false;
+                };
+                after.is_none_or(|after| at >= after) && before.is_none_or(|before| at < before)
+            }
+            Filter::Word(matcher) => {
+                let found = |text: &str| matcher.score(text).is_some();
+                found(&issue.id)
+                    || found(&issue.title)
+                    || issue.description.as_deref().is_some_and(found)
+                    || issue.assignee.as_deref().is_some_and(found)
+                    || issue.labels.iter().any(|l| found(l))
+            }
+            Filter::Text { text, .. } => {
+                let text = &text.to_lowercase();
+                issue.id.to_lowercase().contains(text)
+                    || issue.title.to_lowercase().contains(text)
+                    || issue
+                        .description
+                        .as_ref()
+                        .is_some_and(|d| d.to_lowercase().contains(text))
+                    || issue
+                        .assignee
+                        .as_ref()
+                        .is_some_and(|a| a.to_lowercase().contains(text))
+                    || issue.labels.iter().any(|l| l.to_lowercase().contains(text))
+            }
+        }
+    }
+}
+
+/// A whitespace-separated term, quotes kept, with its char range.
+struct Token {
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+/// Splits the input into terms. Quotes group words, except in regex mode,
+/// where they're part of the pattern.
+fn tokenize(input: &str, mode: SearchMode) -> Result<Vec<Token>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut text = String::new();
+        let mut in_quotes = false;
+        let mut end = start;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() && !in_quotes {
+                break;
+            }
+            if c == '"' && mode != SearchMode::Regex {
+                in_quotes = !in_quotes;
+            }
+            text.push(c);
+            end = i + 1;
+            chars.next();
+        }
+        if in_quotes {
+            return Err(QueryError {
+                message: "Unclosed quote".to_string(),
+                start,
+                end,
+            });
+        }
+        tokens.push(Token { text, start, end });
+    }
+    Ok(tokens)
+}
+
+/// A regex-mode query: the terms around one verbatim pattern. A term before
+/// or after it is a filter if it starts with a known field, an exclusion if
+/// it starts with `-`.
+fn parse_regex_query(
+    input: &str,
+    tokens: &[Token],
+    now: DateTime<Utc>,
+) -> Result<Query, QueryError> {
+    let is_term = |token: &Token| {
+        token.text.starts_with('-')
+            || token
+                .text
+                .split_once(':')
+                .is_some_and(|(field, _)| is_field(field))
+    };
+    let mut terms = Vec::new();
+    let Some(first) = tokens.iter().position(|token| !is_term(token)) else {
+        for token in tokens {
+            terms.push(parse_term(token, SearchMode::Regex, now)?);
+        }
+        return Ok(Query { terms });
+    };
+    let last = tokens
+        .iter()
+        .rposition(|token| !is_term(token))
+        .unwrap_or(first);
+    for token in tokens[..first].iter().chain(&tokens[last + 1..]) {
+        terms.push(parse_term(token, SearchMode::Regex, now)?);
+    }
+    let text = input
+        .chars()
+        .skip(tokens[first].start)
+        .take(tokens[last].end - tokens[first].start)
+        .collect();
+    let filter = Filter::Text {
+        text,
+        quoted: false,
+    };
+    terms.push(Term {
+        negated: false,
+        filter,
+    });
+    Ok(Query { terms })
+}
+
+/// Whether a term's text before the colon names a field.
+fn is_field(name: &str) -> bool {
+    matches!(
+        name.to_lowercase().as_str(),
+        "status" | "is" | "label" | "priority" | "p" | "assignee" | "type" | "created" | "updated"
+    )
+}
+
+fn parse_term(token: &Token, mode: SearchMode, now: DateTime<Utc>) -> Result<Term, QueryError> {
+    let error = |message: String| QueryError {
+        message,
+        start: token.start,
+        end: token.end,
+    };
+
+    let (negated, body) = match token.text.strip_prefix('-') {
+        Some(body) => (true, body),
+        None => (false, token.text.as_str()),
+    };
+    if body.is_empty() {
+        return Err(error("Nothing to exclude after `-`".to_string()));
+    }
+
+    // A colon inside quotes is part of the text, as is one in a regex that
+    // doesn't follow a field name
+    let field_split = body.split_once(':').filter(|(field, _)| match mode {
+        SearchMode::Regex => is_field(field),
+        _ => !field.contains('"'),
+    });
+    let Some((field, value)) = field_split else {
+        if mode == SearchMode::Regex {
+            let matcher = TextMatcher::exclusion(mode, body).map_err(error)?;
+            return Ok(Term {
+                negated,
+                filter: Filter::Word(matcher),
+            });
+        }
+        let quoted = body.starts_with('"');
+        let text = body.trim_matches('"').to_string();
+        if text.is_empty() {
+            return Err(error("Empty quotes".to_string()));
+        }
+        let filter = if negated && !quoted {
+            Filter::Word(TextMatcher::exclusion(mode, &text).map_err(error)?)
+        } else {
+            Filter::Text { text, quoted }
+        };
+        return Ok(Term { negated, filter });
+    };
+
+    let value = value.trim_matches('"');
+    if value.is_empty() {
+        return Err(error(format!("`{}:` needs a value", field)));
+    }
+    let filter = match field.to_lowercase().as_str() {
+        "status" | "is" => match Status::parse(&value.to_lowercase().replace('-', "_")) {
+            Status::Unknown => return Err(error(format!("Unknown status `{}`", value))),
+            status => Filter::Status(status),
+        },
+        "label" => Filter::Label(value.to_lowercase()),
+        "priority" | "p" => {
+            let (cmp, rest) = parse_cmp(value);
+            let rest = rest.trim_start_matches(['p', 'P']);
+            match rest.parse::<u8>() {
+                Ok(p) if p <= 4 => Filter::Priority(cmp, p),
+                _ => return Err(error(format!("Priority must be 0-4, not `{}`", rest))),
+            }
+        }
+        "assignee" => Filter::Assignee(value.to_lowercase()),
+        "type" => match value.to_lowercase().as_str() {
+            "task" => Filter::Type(IssueType::Task),
+            "bug" => Filter::Type(IssueType::Bug),
+            "feature" => Filter::Type(IssueType::Feature),
+            "epic" => Filter::Type(IssueType::Epic),
+            "chore" => Filter::Type(IssueType::Chore),
+            _ => return Err(error(format!("Unknown type `{}`", value))),
+        },
+        "created" | "updated" => {
+            let field = if field.eq_ignore_ascii_case("created") {
+                DateField::Created
+            } else {
+                DateField::Updated
+            };
+            let (after, before) = parse_date_range(value, now).map_err(error)?;
+            Filter::Date {
+                field,
+                after,
+                before,
+            }
+        }
+        _ => {
+            return Err(error(format!(
+                "Unknown field `{}` (quote the term to search for it)",
+                field
+            )))
+        }
+    };
+    Ok(Term { negated, filter })
+}
+
+fn parse_cmp(value: &str) -> (Cmp, &str) {
+    for (prefix, cmp) in [
+        ("<=", Cmp::Le),
+        (">=", Cmp::Ge),
+        ("<", Cmp::Lt),
+        (">", Cmp::Gt),
+        ("=", Cmp::Eq),
+    ] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            return (cmp, rest);
+        }
+    }
+    (Cmp::Eq, value)
+}
+
+type DateRange = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// `<7d` is newer than 7 days ago and `>7d` older; `<2026-10-01` is before
+/// that day and `>2026-10-01` after it. A bare age is `<`, a bare date that day.
+fn parse_date_range(value: &str, now: DateTime<Utc>) -> Result<DateRange, String> {
+    let (cmp, rest) = parse_cmp(value);
+    let out_of_range = || format!("`{}` is further back than dates go", rest);
+    if let Ok(date) = NaiveDate::parse_from_str(rest, "%Y-%m-%d") {
+        let start = date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+        let end = start
+            .checked_add_signed(TimeDelta::days(1))
+            .ok_or_else(|| format!("`{}` is later than dates go", rest))?;
+        return Ok(match cmp {
+            Cmp::Lt => (None, Some(start)),
+            Cmp::Le => (None, Some(end)),
+            Cmp::Eq => (Some(start), Some(end)),
+            Cmp::Ge => (Some(start), None),
+            Cmp::Gt => (Some(end), None),
+        });
+    }
+
+    let age = parse_age(rest)
+        .ok_or_else(|| {
+            format!(
+                "Expected an age like `7d` or a date like `2026-10-01`, not `{}`",
+                rest
+            )
+        })?
+        .ok_or_else(out_of_range)?;
+    let since = now.checked_sub_signed(age).ok_or_else(out_of_range)?;
+    Ok(match cmp {
+        Cmp::Lt | Cmp::Le | Cmp::Eq => (Some(since), None),
+        Cmp::Gt | Cmp::Ge => (None, Some(since)),
+    })
+}
+
+/// `12h`, `7d`, `2w`; None inside when the count is too big for a duration.
+fn parse_age(value: &str) -> Option<Option<TimeDelta>> {
+    let unit = value.chars().last()?;
+    let count: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    match unit {
+        'h' => Some(TimeDelta::try_hours(count)),
+        'd' => Some(TimeDelta::try_days(count)),
+        'w' => Some(TimeDelta::try_weeks(count)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::models::Priority;
+
+    fn issue() -> Issue {
+        Issue {
+            id: "bd-42".to_string(),
+            title: "Fix login redirect".to_string(),
+            description: Some("Users land on the wrong page".to_string()),
+            status: Status::Open,
+            priority: Priority::new(1),
+            issue_type: IssueType::Bug,
+            labels: vec!["Backend".to_string(), "auth".to_string()],
+            assignee: Some("Alice".to_string()),
+            created_at: "2026-10-01T09:00:00Z".to_string(),
+            updated_at: "2026-10-15T12:00:00Z".to_string(),
+            closed_at: None,
+            dependency_count: 0,
+            dependent_count: 0,
+            dependencies: Vec::new(),
+            open_blocker_count: 0,
+            status_history: Vec::new(),
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-18T00:00:00Z".parse().unwrap()
+    }
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        timestamp.parse().unwrap()
+    }
+
+    fn parse_one(input: &str) -> Term {
+        let tokens = tokenize(input, SearchMode::Fuzzy).unwrap();
+        assert_eq!(tokens.len(), 1, "{input}");
+        parse_term(&tokens[0], SearchMode::Fuzzy, now()).unwrap()
+    }
+
+    fn parse_error(input: &str) -> QueryError {
+        tokenize(input, SearchMode::Fuzzy)
+            .and_then(|tokens| {
+                tokens
+                    .iter()
+                    .map(|token| parse_term(token, SearchMode::Fuzzy, now()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .unwrap_err()
+    }
+
+    fn matches(input: &str) -> bool {
+        matches_in(input, SearchMode::Fuzzy)
+    }
+
+    fn matches_in(input: &str, mode: SearchMode) -> bool {
+        Query::parse(input, mode).unwrap().matches(&issue())
+    }
+
+    #[test]
+    fn tokenize_splits_on_whitespace_with_char_ranges() {
+        let tokens = tokenize("  status:open   héllo wörld ", SearchMode::Fuzzy).unwrap();
+        let spans: Vec<_> = tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end))
+            .collect();
+        assert_eq!(
+            spans,
+            [("status:open", 2, 13), ("héllo", 16, 21), ("wörld", 22, 27)]
+        );
+    }
+
+    #[test]
+    fn tokenize_keeps_quoted_spaces_together() {
+        let tokens = tokenize(r#"label:a "exact  text" b"#, SearchMode::Fuzzy).unwrap();
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["label:a", r#""exact  text""#, "b"]);
+    }
+
+    #[test]
+    fn unclosed_quote_spans_the_rest_of_the_term() {
+        let error = parse_error(r#"one "two three"#);
+        assert_eq!(error.message, "Unclosed quote");
+        assert_eq!((error.start, error.end), (4, 14));
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let query = Query::parse("   ", SearchMode::Fuzzy).unwrap();
+        assert!(query.terms.is_empty());
+        assert!(query.matches(&issue()));
+        assert_eq!(query.bare_text(), None);
+    }
+
+    #[test]
+    fn negation_inverts_a_filter() {
+        let term = parse_one("-label:wontfix");
+        assert!(term.negated);
+        assert!(matches!(term.filter, Filter::Label(ref l) if l == "wontfix"));
+        assert!(matches("-label:wontfix"));
+        assert!(!matches("-label:auth"));
+        assert!(!matches("-status:open"));
+    }
+
+    #[test]
+    fn lone_dash_is_an_error() {
+        let error = parse_error("a - b");
+        assert_eq!(error.message, "Nothing to exclude after `-`");
+        assert_eq!((error.start, error.end), (2, 3));
+    }
+
+    #[test]
+    fn bare_words_are_left_to_the_caller() {
+        let query = Query::parse("login -wrong \"land on\" redirect", SearchMode::Fuzzy).unwrap();
+        assert_eq!(query.bare_text().as_deref(), Some("login redirect"));
+        // Bare words don't filter here, but exclusions and phrases do
+        assert!(Query::parse("nothing-like-it", SearchMode::Fuzzy)
+            .unwrap()
+            .matches(&issue()));
+        assert!(!query.matches(&issue()));
+        assert!(matches("\"LAND ON\" -\"right page\""));
+        assert_eq!(
+            Query::parse("\"land on\"", SearchMode::Fuzzy)
+                .unwrap()
+                .phrase_matchers()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn excluded_words_follow_the_search_mode() {
+        // Fuzzy mode excludes the exact text, not everything like it
+        assert!(matches("-lgn"));
+        assert!(!matches("-LOGIN"));
+        assert!(!matches_in("-LOGIN", SearchMode::Exact));
+        assert!(matches_in("-LOGIN", SearchMode::CaseSensitive));
+        assert!(!matches_in("-login", SearchMode::CaseSensitive));
+        assert!(!matches_in("-log.n", SearchMode::Regex));
+        assert!(matches_in("-^fix$", SearchMode::Regex));
+        // Quoted phrases still ignore case
+        assert!(!matches_in("-\"LOGIN\"", SearchMode::CaseSensitive));
+    }
+
+    #[test]
+    fn invalid_excluded_regex_marks_its_term() {
+        let error = Query::parse("fix -(", SearchMode::Regex).unwrap_err();
+        assert!(error.message.starts_with("Invalid regex"), "{}", error);
+        assert_eq!((error.start, error.end), (4, 6));
+    }
+
+    #[test]
+    fn regex_pattern_is_taken_as_typed() {
+        let query = Query::parse(
+            r#"status:open (?:fix|add)  login\s+"re -label:auth"#,
+            SearchMode::Regex,
+        )
+        .unwrap();
+        assert_eq!(
+            query.bare_text().as_deref(),
+            Some(r#"(?:fix|add)  login\s+"re"#)
+        );
+        assert_eq!(query.terms.len(), 3);
+        assert!(!query.matches(&issue()));
+        // Filters and exclusions inside the pattern are part of it
+        let query = Query::parse("fix label:x -y login", SearchMode::Regex).unwrap();
+        assert_eq!(query.bare_text().as_deref(), Some("fix label:x -y login"));
+        assert!(matches_in("is:open -owner:bob", SearchMode::Regex));
+    }
+
+    #[test]
+    fn colon_inside_quotes_is_text() {
+        let term = parse_one(r#""note: see""#);
+        assert!(matches!(
+            term.filter,
+            Filter::Text { ref text, quoted: true } if text == "note: see"
+        ));
+    }
+
+    #[test]
+    fn status_field() {
+        assert!(matches!(
+            parse_one("status:in-progress").filter,
+            Filter::Status(Status::InProgress)
+        ));
+        assert!(matches!(
+            parse_one("is:CLOSED").filter,
+            Filter::Status(Status::Closed)
+        ));
+        assert!(matches("status:open"));
+        assert!(!matches("status:closed"));
+        assert_eq!(parse_error("status:nope").message, "Unknown status `nope`");
+    }
+
+    #[test]
+    fn label_and_assignee_fields_ignore_case() {
+        assert!(matches("label:backend"));
+        assert!(matches("label:AUTH"));
+        assert!(!matches("label:back"));
+        assert!(matches("assignee:alice"));
+        assert!(!matches("assignee:bob"));
+    }
+
+    #[test]
+    fn type_field() {
+        assert!(matches("type:bug"));
+        assert!(!matches("type:Feature"));
+        assert_eq!(parse_error("type:story").message, "Unknown type `story`");
+    }
+
+    #[test]
+    fn priority_field_with_each_cmp() {
+        for (input, cmp, value) in [
+            ("priority:1", Cmp::Eq, 1),
+            ("priority:p1", Cmp::Eq, 1),
+            ("p:P2", Cmp::Eq, 2),
+            ("priority:<2", Cmp::Lt, 2),
+            ("priority:<=2", Cmp::Le, 2),
+            ("priority:=3", Cmp::Eq, 3),
+            ("priority:>=0", Cmp::Ge, 0),
+            ("priority:>4", Cmp::Gt, 4),
+        ] {
+            let filter = parse_one(input).filter;
+            assert!(
+                matches!(filter, Filter::Priority(c, v) if c == cmp && v == value),
+                "{input}"
+            );
+        }
+        assert!(matches("priority:<=1"));
+        assert!(matches("priority:<2"));
+        assert!(!matches("priority:<1"));
+        assert!(matches("priority:>=1"));
+        assert!(!matches("priority:>1"));
+    }
+
+    #[test]
+    fn priority_out_of_range_is_an_error() {
+        let error = parse_error("label:a priority:<=7");
+        assert_eq!(error.message, "Priority must be 0-4, not `7`");
+        assert_eq!((error.start, error.end), (8, 20));
+        assert_eq!(
+            parse_error("p:high").message,
+            "Priority must be 0-4, not `high`"
+        );
+    }
+
+    #[test]
+    fn field_without_value_is_an_error() {
+        let error = parse_error("bug label:");
+        assert_eq!(error.message, "`label:` needs a value");
+        assert_eq!((error.start, error.end), (4, 10));
+    }
+
+    #[test]
+    fn unknown_field_is_an_error() {
+        let error = parse_error("fix owner:me");
+        assert_eq!(
+            error.message,
+            "Unknown field `owner` (quote the term to search for it)"
+        );
+        assert_eq!((error.start, error.end), (4, 12));
+    }
+
+    #[test]
+    fn parse_cmp_prefers_two_char_operators() {
+        assert_eq!(parse_cmp("<=1"), (Cmp::Le, "1"));
+        assert_eq!(parse_cmp(">=1"), (Cmp::Ge, "1"));
+        assert_eq!(parse_cmp("<1"), (Cmp::Lt, "1"));
+        assert_eq!(parse_cmp(">1"), (Cmp::Gt, "1"));
+        assert_eq!(parse_cmp("=1"), (Cmp::Eq, "1"));
+        assert_eq!(parse_cmp("1"), (Cmp::Eq, "1"));
+    }
+
+    #[test]
+    fn age_ranges_count_back_from_now() {
+        let week_ago = Some(at("2026-10-11T00:00:00Z"));
+        assert_eq!(parse_date_range("<7d", now()), Ok((week_ago, None)));
+        assert_eq!(parse_date_range("7d", now()), Ok((week_ago, None)));
+        assert_eq!(parse_date_range(">7d", now()), Ok((None, week_ago)));
+        assert_eq!(parse_date_range(">=1w", now()), Ok((None, week_ago)));
+        let half_day = Some(at("2026-10-17T12:00:00Z"));
+        assert_eq!(parse_date_range("<12h", now()), Ok((half_day, None)));
+    }
+
+    #[test]
+    fn date_ranges_cover_whole_days() {
+        let start = Some(at("2026-10-01T00:00:00Z"));
+        let end = Some(at("2026-10-02T00:00:00Z"));
+        assert_eq!(parse_date_range("2026-10-01", now()), Ok((start, end)));
+        assert_eq!(parse_date_range("<2026-10-01", now()), Ok((None, start)));
+        assert_eq!(parse_date_range("<=2026-10-01", now()), Ok((None, end)));
+        assert_eq!(parse_date_range(">=2026-10-01", now()), Ok((start, None)));
+        assert_eq!(parse_date_range(">2026-10-01", now()), Ok((end, None)));
+    }
+
+    #[test]
+    fn date_fields_match_their_own_timestamp() {
+        let created = parse_one("created:2026-10-01");
+        assert!(created.filter.matches(&issue()));
+        let updated = parse_one("updated:2026-10-01");
+        assert!(!updated.filter.matches(&issue()));
+        assert!(parse_one("updated:>2026-10-14").filter.matches(&issue()));
+        assert!(!parse_one("created:>2026-10-14").filter.matches(&issue()));
+    }
+
+    #[test]
+    fn bad_ages_are_errors() {
+        let error = parse_error("updated:<7y");
+        assert_eq!(
+            error.message,
+            "Expected an age like `7d` or a date like `2026-10-01`, not `7y`"
+        );
+        assert_eq!((error.start, error.end), (0, 11));
+        assert!(parse_date_range("<d", now()).is_err());
+        assert!(parse_date_range("<", now()).is_err());
+    }
+
+    #[test]
+    fn out_of_range_ages_are_errors_not_panics() {
+        for value in [
+            "<999999999d",
+            "<99999999999999w",
+            ">9223372036854775807h",
+            "<99999999999d",
+        ] {
+            let error = parse_date_range(value, now()).unwrap_err();
+            assert!(
+                error.contains("further back than dates go"),
+                "{value}: {error}"
+            );
+        }
+        let error = parse_error("bug updated:<999999999d");
+        assert_eq!((error.start, error.end), (4, 23));
+        assert!(Query::parse("updated:<999999999d", SearchMode::Fuzzy).is_err());
+        // The day after the last date there is
+        let error = parse_date_range("<=+262142-12-31", now()).unwrap_err();
+        assert!(error.contains("later than dates go"), "{error}");
+    }
+}
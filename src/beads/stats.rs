@@ -0,0 +1,458 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+
+use super::models::{Issue, Status};
+
+/// Number of issues closed on each of the `days` days ending today, oldest first.
+pub fn closed_per_day(issues: &[Issue], days: usize, now: DateTime<Utc>) -> Vec<u64> {
+    let today = now.date_naive();
+    let mut counts = vec![0; days];
+    for closed in issues.iter().filter_map(Issue::closed_time) {
+        let age = (today - closed.date_naive()).num_days();
+        if (0..days as i64).contains(&age) {
+            counts[days - 1 - age as usize] += 1;
+        }
+    }
+    counts
+}
+
+/// Created→closed durations of every closed issue.
+pub fn cycle_times(issues: &[Issue]) -> Vec<Duration> {
+    issues
+        .iter()
+        .filter_map(|i| Some(i.closed_time()? - i.created_time()?))
+        .filter(|d| *d >= Duration::zero())
+        .collect()
+}
+
+pub fn average(durations: &[Duration]) -> Option<Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+    let total: i64 = durations.iter().map(Duration::num_seconds).sum();
+    Some(Duration::seconds(total / durations.len() as i64))
+}
+
+pub fn median(durations: &[Duration]) -> Option<Duration> {
+    let mut sorted = durations.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[mid]),
+        _ => Some((sorted[mid - 1] + sorted[mid]) / 2),
+    }
+}
+
+/// Open (not closed) issues, oldest first.
+pub fn oldest_open(issues: &[Issue], limit: usize) -> Vec<&Issue> {
+    let mut open: Vec<&Issue> = issues
+        .iter()
+        .filter(|i| i.status != Status::Closed)
+        .collect();
+    // Issues without a parseable timestamp go last
+    open.sort_by_key(|i| (i.created_time().is_none(), i.created_time()));
+    open.truncate(limit);
+    open
+}
+
+/// Daily scope/closed/open counts for a set of issues, from the first
+/// creation date through `today`.
+#[derive(Debug, Clone)]
+pub struct BurnSeries {
+    pub start: NaiveDate,
+    /// Per day, oldest first.
+    pub scope: Vec<u64>,
+    pub closed: Vec<u64>,
+}
+
+impl BurnSeries {
+    pub fn build(issues: &[&Issue], today: NaiveDate) -> Option<Self> {
+        let created: Vec<NaiveDate> = issues
+            .iter()
+            .filter_map(|i| i.created_time())
+            .map(|t| t.date_naive())
+            .collect();
+        let closed: Vec<NaiveDate> = issues
+            .iter()
+            .filter_map(|i| i.closed_time())
+            .map(|t| t.date_naive())
+            .collect();
+        let start = created.iter().min().copied()?.min(today);
+
+        let mut series = BurnSeries {
+            start,
+            scope: Vec::new(),
+            closed: Vec::new(),
+        };
+        for day in start.iter_days().take_while(|d| *d <= today) {
+            series
+                .scope
+                .push(created.iter().filter(|d| **d <= day).count() as u64);
+            series
+                .closed
+                .push(closed.iter().filter(|d| **d <= day).count() as u64);
+        }
+        Some(series)
+    }
+
+    pub fn open(&self) -> Vec<u64> {
+        self.scope
+            .iter()
+            .zip(&self.closed)
+            .map(|(scope, closed)| scope.saturating_sub(*closed))
+            .collect()
+    }
+
+    /// Closed issues per day over the last `window` days.
+    pub fn recent_rate(&self, window: usize) -> f64 {
+        let window = window.min(self.closed.len().saturating_sub(1));
+        if window == 0 {
+            return 0.0;
+        }
+        let last = self.closed[self.closed.len() - 1];
+        let before = self.closed[self.closed.len() - 1 - window];
+        last.saturating_sub(before) as f64 / window as f64
+    }
+
+    /// Day (offset from `start`) when the remaining open issues are projected
+    /// to be closed at the recent rate. None if nothing is being closed.
+    pub fn projected_completion(&self, window: usize) -> Option<usize> {
+        let today = self.scope.len().checked_sub(1)?;
+        let remaining = self.open()[today];
+        if remaining == 0 {
+            return Some(today);
+        }
+        let rate = self.recent_rate(window);
+        if rate <= 0.0 {
+            return None;
+        }
+        Some(today + (remaining as f64 / rate).ceil() as usize)
+    }
+}
+
+/// Daily open/in-progress/closed counts for a cumulative flow diagram.
+/// Blocked, deferred and other statuses count as open.
+#[derive(Debug, Clone)]
+pub struct FlowSeries {
+    pub start: NaiveDate,
+    /// Per day, oldest first.
+    pub open: Vec<u64>,
+    pub in_progress: Vec<u64>,
+    pub closed: Vec<u64>,
+}
+
+impl FlowSeries {
+    /// Status counts at the end of each day from `start` through `end`.
+    pub fn build(issues: &[Issue], start: NaiveDate, end: NaiveDate) -> Self {
+        let mut series = FlowSeries {
+            start,
+            open: Vec::new(),
+            in_progress: Vec::new(),
+            closed: Vec::new(),
+        };
+        for day in start.iter_days().take_while(|d| *d <= end) {
+            let end_of_day = (day + Duration::days(1)).and_time(NaiveTime::MIN).and_utc();
+            let (mut open, mut in_progress, mut closed) = (0, 0, 0);
+            for status in issues.iter().filter_map(|i| i.status_at(end_of_day)) {
+                match status {
+                    Status::Closed => closed += 1,
+                    Status::InProgress => in_progress += 1,
+                    _ => open += 1,
+                }
+            }
+            series.open.push(open);
+            series.in_progress.push(in_progress);
+            series.closed.push(closed);
+        }
+        series
+    }
+
+    /// Days in the series.
+    pub fn len(&self) -> usize {
+        self.open.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.open.is_empty()
+    }
+
+    /// Largest stacked total over the range.
+    pub fn max_total(&self) -> u64 {
+        (0..self.len())
+            .map(|d| self.open[d] + self.in_progress[d] + self.closed[d])
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The day the earliest issue was created.
+pub fn first_created(issues: &[Issue]) -> Option<NaiveDate> {
+    issues
+        .iter()
+        .filter_map(Issue::created_time)
+        .map(|t| t.date_naive())
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::models::{IssueType, Priority, StatusChange};
+
+    fn issue(id: &str, created: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            description: None,
+            status: Status::Open,
+            priority: Priority::new(2),
+            issue_type: IssueType::Task,
+            labels: Vec::new(),
+            assignee: None,
+            created_at: created.to_string(),
+            updated_at: created.to_string(),
+            closed_at: None,
+            dependency_count: 0,
+            dependent_count: 0,
+            dependencies: Vec::new(),
+            open_blocker_count: 0,
+            status_history: Vec::new(),
+        }
+    }
+
+    fn closed(mut issue: Issue, at: &str) -> Issue {
+        issue.status = Status::Closed;
+        issue.closed_at = Some(at.to_string());
+        issue.updated_at = at.to_string();
+        issue
+    }
+
+    fn hours(hours: &[i64]) -> Vec<Duration> {
+        hours.iter().map(|&h| Duration::hours(h)).collect()
+    }
+
+    #[test]
+    fn average_of_nothing_is_none() {
+        assert_eq!(average(&[]), None);
+        assert_eq!(median(&[]), None);
+    }
+
+    #[test]
+    fn average_is_the_mean() {
+        assert_eq!(average(&hours(&[1, 2, 6])), Some(Duration::hours(3)));
+        assert_eq!(average(&hours(&[5])), Some(Duration::hours(5)));
+    }
+
+    #[test]
+    fn median_of_odd_count_is_the_middle_value() {
+        assert_eq!(median(&hours(&[9, 1, 4])), Some(Duration::hours(4)));
+    }
+
+    #[test]
+    fn median_of_even_count_averages_the_middle_two() {
+        assert_eq!(median(&hours(&[10, 1, 2, 100])), Some(Duration::hours(6)));
+    }
+
+    #[test]
+    fn cycle_times_skip_open_and_backwards_issues() {
+        let issues = [
+            closed(issue("a", "2026-10-01T00:00:00Z"), "2026-10-03T12:00:00Z"),
+            issue("b", "2026-10-01T00:00:00Z"),
+            // Closed before it was created, from a clock mix-up
+            closed(issue("c", "2026-10-05T00:00:00Z"), "2026-10-04T00:00:00Z"),
+        ];
+        assert_eq!(cycle_times(&issues), hours(&[60]));
+    }
+
+    #[test]
+    fn closed_per_day_counts_the_days_ending_today() {
+        let now = "2026-10-18T15:00:00Z".parse().unwrap();
+        let issues = [
+            closed(issue("a", "2026-10-01T00:00:00Z"), "2026-10-18T09:00:00Z"),
+            closed(issue("b", "2026-10-01T00:00:00Z"), "2026-10-18T01:00:00Z"),
+            closed(issue("c", "2026-10-01T00:00:00Z"), "2026-10-16T23:00:00Z"),
+            // Outside the window
+            closed(issue("d", "2026-10-01T00:00:00Z"), "2026-10-15T23:00:00Z"),
+            issue("e", "2026-10-01T00:00:00Z"),
+        ];
+        assert_eq!(closed_per_day(&issues, 3, now), [1, 0, 2]);
+    }
+
+    #[test]
+    fn oldest_open_sorts_by_age_and_puts_unknown_dates_last() {
+        let issues = [
+            issue("new", "2026-10-10T00:00:00Z"),
+            issue("undated", "soon"),
+            closed(
+                issue("done", "2026-01-01T00:00:00Z"),
+                "2026-02-01T00:00:00Z",
+            ),
+            issue("old", "2026-09-01T00:00:00Z"),
+        ];
+        let ids: Vec<_> = oldest_open(&issues, 5)
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(ids, ["old", "new", "undated"]);
+        assert_eq!(oldest_open(&issues, 1)[0].id, "old");
+    }
+
+    fn day(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    /// a open from the 1st, b open 2nd–3rd, c open 3rd–4th; today the 5th.
+    fn burn() -> BurnSeries {
+        let issues = [
+            issue("a", "2026-10-01T09:00:00Z"),
+            closed(issue("b", "2026-10-02T09:00:00Z"), "2026-10-03T09:00:00Z"),
+            closed(issue("c", "2026-10-03T09:00:00Z"), "2026-10-04T09:00:00Z"),
+        ];
+        let issues: Vec<&Issue> = issues.iter().collect();
+        BurnSeries::build(&issues, day("2026-10-05")).unwrap()
+    }
+
+    #[test]
+    fn burn_series_runs_from_the_first_creation_through_today() {
+        let series = burn();
+        assert_eq!(series.start, day("2026-10-01"));
+        assert_eq!(series.scope, [1, 2, 3, 3, 3]);
+        assert_eq!(series.closed, [0, 0, 1, 2, 2]);
+        assert_eq!(series.open(), [1, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn burn_series_needs_a_dated_issue() {
+        let undated = issue("a", "someday");
+        assert!(BurnSeries::build(&[&undated], day("2026-10-05")).is_none());
+        assert!(BurnSeries::build(&[], day("2026-10-05")).is_none());
+    }
+
+    #[test]
+    fn burn_series_starts_today_for_issues_created_later() {
+        let later = issue("a", "2026-10-09T09:00:00Z");
+        let series = BurnSeries::build(&[&later], day("2026-10-05")).unwrap();
+        assert_eq!(series.start, day("2026-10-05"));
+        assert_eq!(series.scope, [0]);
+    }
+
+    #[test]
+    fn recent_rate_is_closes_per_day_over_the_window() {
+        let series = burn();
+        assert_eq!(series.recent_rate(2), 0.5);
+        assert_eq!(series.recent_rate(1), 0.0);
+        // Clamped to the days the series has
+        assert_eq!(series.recent_rate(30), 0.5);
+        assert_eq!(series.recent_rate(0), 0.0);
+    }
+
+    #[test]
+    fn projection_divides_what_is_left_by_the_recent_rate() {
+        // One open issue at half an issue a day, from day 4
+        assert_eq!(burn().projected_completion(2), Some(6));
+    }
+
+    #[test]
+    fn no_projection_without_recent_closes() {
+        assert_eq!(burn().projected_completion(1), None);
+    }
+
+    #[test]
+    fn finished_work_projects_to_today() {
+        let issues = [closed(
+            issue("a", "2026-10-01T09:00:00Z"),
+            "2026-10-02T09:00:00Z",
+        )];
+        let issues: Vec<&Issue> = issues.iter().collect();
+        let series = BurnSeries::build(&issues, day("2026-10-03")).unwrap();
+        assert_eq!(series.projected_completion(7), Some(2));
+    }
+
+    /// Flow counts per day as (open, in progress, closed).
+    fn flow(issues: &[Issue], start: &str, end: &str) -> Vec<(u64, u64, u64)> {
+        let series = FlowSeries::build(issues, day(start), day(end));
+        (0..series.len())
+            .map(|d| (series.open[d], series.in_progress[d], series.closed[d]))
+            .collect()
+    }
+
+    #[test]
+    fn flow_infers_past_statuses_from_timestamps() {
+        let mut started = issue("c", "2026-10-02T09:00:00Z");
+        started.status = Status::InProgress;
+        started.updated_at = "2026-10-03T10:00:00Z".to_string();
+        let mut blocked = issue("d", "2026-10-03T09:00:00Z");
+        blocked.status = Status::Blocked;
+        let issues = [
+            issue("a", "2026-10-01T09:00:00Z"),
+            closed(issue("b", "2026-10-01T09:00:00Z"), "2026-10-02T12:00:00Z"),
+            started,
+            blocked,
+        ];
+        assert_eq!(
+            flow(&issues, "2026-10-01", "2026-10-03"),
+            [(2, 0, 0), (2, 0, 1), (2, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn flow_replays_the_status_history() {
+        let mut reopened = issue("a", "2026-10-01T09:00:00Z");
+        reopened.status_history = vec![
+            StatusChange {
+                at: "2026-10-02T10:00:00Z".to_string(),
+                from: Some(Status::InProgress),
+                to: Status::Closed,
+            },
+            StatusChange {
+                at: "2026-10-03T10:00:00Z".to_string(),
+                from: Some(Status::Closed),
+                to: Status::Open,
+            },
+        ];
+        assert_eq!(
+            flow(&[reopened], "2026-10-01", "2026-10-03"),
+            [(0, 1, 0), (0, 0, 1), (1, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn flow_leaves_out_issues_not_yet_created() {
+        let issues = [issue("a", "2026-10-05T09:00:00Z")];
+        assert_eq!(
+            flow(&issues, "2026-10-04", "2026-10-05"),
+            [(0, 0, 0), (1, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn flow_of_a_backwards_range_is_empty() {
+        let issues = [issue("a", "2026-10-01T09:00:00Z")];
+        let series = FlowSeries::build(&issues, day("2026-10-05"), day("2026-10-04"));
+        assert!(series.is_empty());
+        assert_eq!(series.max_total(), 0);
+    }
+
+    #[test]
+    fn max_total_is_the_tallest_stack() {
+        let issues = [
+            issue("a", "2026-10-01T09:00:00Z"),
+            closed(issue("b", "2026-10-02T09:00:00Z"), "2026-10-02T12:00:00Z"),
+            issue("c", "2026-10-03T09:00:00Z"),
+        ];
+        let series = FlowSeries::build(&issues, day("2026-10-01"), day("2026-10-02"));
+        assert_eq!(series.len(), 2);
+        assert_eq!(series.max_total(), 2);
+    }
+
+    #[test]
+    fn first_created_skips_undated_issues() {
+        let issues = [
+            issue("a", "2026-10-03T09:00:00Z"),
+            issue("b", "whenever"),
+            issue("c", "2026-09-30T23:00:00Z"),
+        ];
+        assert_eq!(first_created(&issues), Some(day("2026-09-30")));
+        assert_eq!(first_created(&issues[1..2]), None);
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Per-project settings, read from `.beads/brui.json`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub wip_limits: WipLimits,
+    /// Default number of blocking hops shown around the focused issue in the
+    /// dependency graph.
+    pub graph_depth: Option<usize>,
+    /// Most issues loaded from `bd list`. Unset loads them all.
+    pub issue_limit: Option<usize>,
+    /// Named board views, shown as tabs after the one brui was launched with.
+    pub views: Vec<SavedView>,
+}
+
+/// A named board view.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SavedView {
+    pub name: String,
+    /// Passed to `bd list --label`; unset loads every issue.
+    pub label: Option<String>,
+    /// Only cards whose ID, title, labels or assignee contain this text
+    /// (case-insensitive).
+    pub query: Option<String>,
+    /// Board columns to show, from `open`, `in_progress` and `done`. Empty
+    /// shows all three.
+    pub columns: Vec<String>,
+    /// Card order within each column, by a table column name (`priority`,
+    /// `updated`, ...). A leading `-` sorts descending.
+    pub sort: Option<String>,
+}
+
+/// Work-in-progress limits. Unset limits are not enforced.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct WipLimits {
+    pub open: Option<usize>,
+    pub in_progress: Option<usize>,
+    pub done: Option<usize>,
+    /// Maximum in-progress issues per assignee.
+    pub per_assignee: Option<usize>,
+}
+
+impl Config {
+    pub const FILE_NAME: &'static str = "brui.json";
+
+    /// Loads the config from the beads dir, falling back to defaults when the
+    /// file doesn't exist.
+    pub fn load(beads_dir: &Path) -> Result<Self> {
+        let path = beads_dir.join(Self::FILE_NAME);
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::beads::query::SearchMode;
+
+/// Recent and named searches, kept per project in `.beads/brui-searches.json`.
+/// Unlike the config, brui writes this file itself.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchHistory {
+    /// Most recent first, without duplicates.
+    pub recent: Vec<RecentSearch>,
+    pub saved: Vec<SavedSearch>,
+}
+
+/// A query with the mode its bare words were matched in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "StoredSearch")]
+pub struct RecentSearch {
+    pub query: String,
+    pub mode: SearchMode,
+}
+
+/// A recent search as stored; files from before searches kept their mode
+/// hold just the query.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredSearch {
+    Query(String),
+    Search {
+        query: String,
+        #[serde(default)]
+        mode: SearchMode,
+    },
+}
+
+impl From<StoredSearch> for RecentSearch {
+    fn from(stored: StoredSearch) -> Self {
+        match stored {
+            StoredSearch::Query(query) => RecentSearch {
+                query,
+                mode: SearchMode::default(),
+            },
+            StoredSearch::Search { query, mode } => RecentSearch { query, mode },
+        }
+    }
+}
+
+/// A search query saved under a name, to apply from the board as a filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    pub mode: SearchMode,
+}
+
+impl SearchHistory {
+    pub const FILE_NAME: &'static str = "brui-searches.json";
+    /// Recent queries kept; older ones are dropped.
+    pub const MAX_RECENT: usize = 50;
+
+    /// Loads the history from the beads dir, starting empty when the file
+    /// doesn't exist.
+    pub fn load(beads_dir: &Path) -> Result<Self> {
+        let path = beads_dir.join(Self::FILE_NAME);
+        if !path.exists() {
+            return Ok(SearchHistory::default());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Writes the history through a temporary file, so a crash part way
+    /// through leaves the old file rather than a truncated one.
+    pub fn save(&self, beads_dir: &Path) -> Result<()> {
+        let path = beads_dir.join(Self::FILE_NAME);
+        let tmp_path = path.with_extension("json.tmp");
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(&tmp_path, contents)
+            .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+        std::fs::rename(&tmp_path, &path)
+            .with_context(|| format!("Failed to replace {}", path.display()))
+    }
+
+    /// Moves the search to the front of the recent list.
+    pub fn record(&mut self, search: RecentSearch) {
+        self.recent.retain(|s| *s != search);
+        self.recent.insert(0, search);
+        self.recent.truncate(Self::MAX_RECENT);
+    }
+
+    /// Saves a query under a name, replacing any search with that name.
+    pub fn save_search(&mut self, name: &str, query: &str, mode: SearchMode) {
+        let search = SavedSearch {
+            name: name.to_string(),
+            query: query.to_string(),
+            mode,
+        };
+        match self.saved.iter_mut().find(|s| s.name == name) {
+            Some(existing) => *existing = search,
+            None => self.saved.push(search),
+        }
+    }
+}
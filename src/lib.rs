@@ -0,0 +1,4 @@
+//! The beads data layer: loading issues through `bd`, and the graph, stats
+//! and search built over them. A library so the benches can reach it.
+
+pub mod beads;
@@ -1,11 +1,13 @@
-mod beads;
+use brui::beads;
+mod config;
+mod history;
 mod ui;
 mod watcher;
 
 use anyhow::Result;
 use clap::Parser;
 use crossterm::{
-    event::Event,
+    event::{DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -13,6 +15,7 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 
 use beads::BeadsClient;
+use config::Config;
 use ui::{App, View};
 use watcher::FileWatcher;
 
@@ -40,6 +43,11 @@ fn main() -> Result<()> {
     // Find beads project
     let beads_dir = BeadsClient::find_beads_dir()?;
     let client = BeadsClient::new(beads_dir.clone())?;
+    // A mistake in the config isn't worth refusing to start over
+    let (config, config_error) = match Config::load(&beads_dir) {
+        Ok(config) => (config, None),
+        Err(e) => (Config::default(), Some(format!("Config not loaded: {e:#}"))),
+    };
 
     // Set up label filter
     let label_filter = if cli.all {
@@ -56,12 +64,15 @@ fn main() -> Result<()> {
     };
 
     // Create app
-    let mut app = App::new(client, label_filter)?;
+    let mut app = App::new(client, label_filter, config)?;
+    if config_error.is_some() {
+        app.status_message = config_error;
+    }
 
     // Set up terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -70,7 +81,11 @@ fn main() -> Result<()> {
 
     // Restore terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
 
     res
@@ -82,24 +97,47 @@ fn run_app(
     watcher: Option<FileWatcher>,
 ) -> Result<()> {
     loop {
-        // Draw UI and capture scroll info from detail view
-        let mut new_scroll_max: u16 = 0;
-        let mut new_viewport_height: u16 = 0;
-
-        terminal.draw(|f| match app.current_view {
-            View::Board => ui::render_board(f, app),
-            View::Detail => {
-                let (sm, vh) = ui::render_detail(f, app);
-                new_scroll_max = sm;
-                new_viewport_height = vh;
+        // Draw UI and capture scroll info and links from detail view and layouts for the mouse
+        let mut new_detail_layout = ui::detail::DetailLayout::default();
+        let mut new_board_layout = None;
+        let mut new_search_layout = None;
+
+        terminal.draw(|f| {
+            match app.current_view {
+                View::Board => new_board_layout = Some(ui::render_board(f, app)),
+                View::Detail => new_detail_layout = ui::render_detail(f, app),
+                View::Search => new_search_layout = Some(ui::render_search(f, app)),
+                View::Epics => ui::render_epics(f, app),
+                View::Graph => ui::render_graph(f, app),
+                View::Ready => ui::render_ready(f, app),
+                View::Blocked => ui::render_blocked(f, app),
+                View::Table => ui::render_table(f, app),
+                View::Dashboard => ui::render_dashboard(f, app),
+                View::Burndown => ui::render_burndown(f, app),
+                View::Flow => ui::render_flow(f, app),
+            }
+            if app.palette.is_some() {
+                ui::render_palette(f, app);
+            }
+            if app.jump.is_some() {
+                ui::render_jump(f, app);
+            }
+            if app.show_help {
+                ui::render_help(f, app);
             }
-            View::Search => ui::render_search(f, app),
         })?;
 
         // Update scroll state after render
-        app.detail_scroll_max = new_scroll_max;
-        app.detail_viewport_height = new_viewport_height;
+        app.detail_scroll_max = new_detail_layout.scroll_max;
+        app.detail_viewport_height = new_detail_layout.viewport_height;
+        app.detail_links = new_detail_layout.links;
         app.detail_scroll = app.detail_scroll.min(app.detail_scroll_max);
+        if let Some(layout) = new_board_layout {
+            app.board_layout = layout;
+        }
+        if let Some(layout) = new_search_layout {
+            app.search_layout = layout;
+        }
 
         // Check for file changes
         if let Some(ref w) = watcher {
@@ -109,8 +147,10 @@ fn run_app(
         }
 
         // Handle events
-        if let Some(Event::Key(key)) = app.poll_event()? {
-            app.handle_key(key)?;
+        match app.poll_event()? {
+            Some(Event::Key(key)) => app.handle_key(key)?,
+            Some(Event::Mouse(mouse)) => app.handle_mouse(mouse)?,
+            _ => {}
         }
 
         // Check if should quit
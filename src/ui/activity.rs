@@ -0,0 +1,83 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use super::app::App;
+use super::theme::*;
+use crate::beads::activity::{Activity, Change};
+
+/// Renders the activity feed beside the board, newest change first.
+pub fn render_activity(f: &mut Frame, area: Rect, app: &App) {
+    let border = if app.activity_focus {
+        COLOR_BORDER_ACTIVE
+    } else {
+        COLOR_BORDER
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Activity ({}) ", app.activity.len()))
+        .style(Style::default().fg(border));
+
+    if app.activity.is_empty() {
+        let paragraph =
+            Paragraph::new("No changes yet. Changes bd makes to the loaded issues appear here.")
+                .block(block)
+                .style(Style::default().fg(COLOR_SECONDARY_TEXT))
+                .wrap(Wrap { trim: true });
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let width = block.inner(area).width as usize;
+    let items: Vec<ListItem> = app
+        .activity
+        .iter()
+        .rev()
+        .map(|activity| format_activity_item(activity, width))
+        .collect();
+
+    let mut list = List::new(items).block(block);
+    if app.activity_focus {
+        list = list.highlight_style(Style::default().bg(COLOR_SELECTED_BG));
+    }
+    let mut state = ListState::default().with_selected(Some(app.activity_selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn format_activity_item(activity: &Activity, width: usize) -> ListItem<'static> {
+    let change_color = match activity.change {
+        Change::Created => COLOR_OPEN,
+        Change::Closed => COLOR_DONE,
+        Change::Status { to, .. } => status_color(to),
+        Change::Priority { to, .. } => priority_color(to.0),
+        Change::Labels { .. } => COLOR_HELP_TEXT,
+    };
+    let header = Line::from(vec![
+        Span::styled(
+            format!("{} ", activity.at.format("%H:%M:%S")),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ),
+        Span::styled(
+            format!("{} ", activity.issue_id),
+            Style::default()
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            activity.change.to_string(),
+            Style::default().fg(change_color),
+        ),
+    ]);
+    let title: String = activity
+        .title
+        .chars()
+        .take(width.saturating_sub(2))
+        .collect();
+    let title = Line::from(format!("  {}", title));
+    ListItem::new(vec![header, title])
+}
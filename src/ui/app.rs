@@ -1,16 +1,78 @@
-use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
-use std::time::Duration;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Days, Local, NaiveDate, Utc};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, HashSet};
+use std::time::{Duration, Instant};
 
-use crate::beads::{BeadsClient, Issue, Status};
+use super::board::BoardLayout;
+use super::detail::DescriptionLink;
+use super::graph::{graph_layout, DagLayout, GraphMove};
+use super::jump::{completion, jump_matches, JumpState};
+use super::keymap::{self, Action};
+use super::palette::{palette_matches, Command, PaletteState};
+use super::search::SearchLayout;
+use super::table::TableColumn;
+use crate::beads::graph::TreeRow;
+use crate::beads::index::SearchIndex;
+use crate::beads::activity::{self, Activity};
+use crate::beads::models::parse_timestamp;
+use crate::beads::query::{Query, QueryError, SearchMode, TextMatcher};
+use crate::beads::{stats, BeadsClient, Issue, IssueGraph, Status};
+use crate::config::{Config, SavedView};
+use crate::history::{RecentSearch, SearchHistory};
+
+/// Two clicks on the same issue within this window count as a double-click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+/// Lines scrolled in the detail view per mouse wheel tick.
+const WHEEL_SCROLL_LINES: u16 = 3;
+/// Blocking hops shown around the focused issue unless configured otherwise.
+const DEFAULT_GRAPH_DEPTH: usize = 2;
+const MAX_GRAPH_DEPTH: usize = 10;
+/// Date ranges offered by the cumulative flow view, in days; None is the
+/// whole history.
+pub const FLOW_SPANS: [Option<usize>; 5] = [Some(14), Some(30), Some(90), Some(180), None];
+const DEFAULT_FLOW_SPAN: usize = 1;
+/// Share of the board's width given to the preview pane, in percent.
+const DEFAULT_PREVIEW_WIDTH: u16 = 40;
+const MIN_PREVIEW_WIDTH: u16 = 20;
+const MAX_PREVIEW_WIDTH: u16 = 70;
+const PREVIEW_WIDTH_STEP: u16 = 5;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum View {
     Board,
     Detail,
     Search,
+    Epics,
+    Graph,
+    Ready,
+    Blocked,
+    Table,
+    Dashboard,
+    Burndown,
+    Flow,
+}
+
+impl View {
+    pub fn name(self) -> &'static str {
+        match self {
+            View::Board => "Board",
+            View::Detail => "Detail",
+            View::Search => "Search",
+            View::Epics => "Epics",
+            View::Graph => "Dependency graph",
+            View::Ready => "Ready work",
+            View::Blocked => "Blocked work",
+            View::Table => "Table",
+            View::Dashboard => "Dashboard",
+            View::Burndown => "Burndown",
+            View::Flow => "Cumulative flow",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,19 +83,15 @@ pub enum Column {
 }
 
 impl Column {
-    pub fn next(&self) -> Self {
-        match self {
-            Column::Open => Column::InProgress,
-            Column::InProgress => Column::Done,
-            Column::Done => Column::Open,
-        }
-    }
+    pub const ALL: [Column; 3] = [Column::Open, Column::InProgress, Column::Done];
 
-    pub fn prev(&self) -> Self {
-        match self {
-            Column::Open => Column::Done,
-            Column::InProgress => Column::Open,
-            Column::Done => Column::InProgress,
+    /// Looks a column up by its config name.
+    pub fn from_name(name: &str) -> Option<Column> {
+        match name {
+            "open" => Some(Column::Open),
+            "in_progress" => Some(Column::InProgress),
+            "done" => Some(Column::Done),
+            _ => None,
         }
     }
 
@@ -44,290 +102,2000 @@ impl Column {
             Column::Done => vec![Status::Closed],
         }
     }
+
+    /// Status given to an issue moved into this column.
+    pub fn target_status(&self) -> Status {
+        match self {
+            Column::Open => Status::Open,
+            Column::InProgress => Status::InProgress,
+            Column::Done => Status::Closed,
+        }
+    }
+
+    pub fn for_status(status: Status) -> Self {
+        match status {
+            Status::Open | Status::Blocked | Status::Deferred | Status::Unknown => Column::Open,
+            Status::InProgress => Column::InProgress,
+            Status::Closed => Column::Done,
+        }
+    }
+}
+
+/// A card being dragged between columns with the mouse.
+#[derive(Debug, Clone)]
+pub struct DragState {
+    pub issue_id: String,
+    pub from: Column,
+    pub over: Option<Column>,
+}
+
+/// How much detail each card on the board shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardDensity {
+    Compact,
+    Expanded,
+}
+
+impl CardDensity {
+    pub fn toggle(&self) -> Self {
+        match self {
+            CardDensity::Compact => CardDensity::Expanded,
+            CardDensity::Expanded => CardDensity::Compact,
+        }
+    }
+}
+
+/// A board tab: the launch view or a saved view from the config, with its
+/// own selection.
+#[derive(Debug, Clone)]
+pub struct BoardTab {
+    pub name: String,
+    pub label: Option<String>,
+    /// Lowercased free-text card filter
+    pub query: Option<String>,
+    /// Visible columns, in board order; never empty
+    pub columns: Vec<Column>,
+    /// Card order within columns, and whether it's descending
+    pub sort: Option<(TableColumn, bool)>,
+    pub selected_column: Column,
+    pub selected_index: usize,
+}
+
+impl BoardTab {
+    fn new(name: String, label: Option<String>) -> Self {
+        BoardTab {
+            name,
+            label,
+            query: None,
+            columns: Column::ALL.to_vec(),
+            sort: None,
+            selected_column: Column::Open,
+            selected_index: 0,
+        }
+    }
+
+    fn from_config(view: &SavedView) -> Result<Self> {
+        let mut tab = BoardTab::new(view.name.clone(), view.label.clone());
+        tab.query = view
+            .query
+            .as_deref()
+            .filter(|q| !q.is_empty())
+            .map(str::to_lowercase);
+        if !view.columns.is_empty() {
+            let mut columns = Vec::new();
+            for name in &view.columns {
+                match Column::from_name(name) {
+                    Some(column) => columns.push(column),
+                    None => bail!("Unknown column `{}` in view `{}`", name, view.name),
+                }
+            }
+            // Board order, regardless of the order in the config
+            tab.columns = Column::ALL
+                .into_iter()
+                .filter(|c| columns.contains(c))
+                .collect();
+            tab.selected_column = tab.columns[0];
+        }
+        if let Some(ref sort) = view.sort {
+            let (name, desc) = match sort.strip_prefix('-') {
+                Some(name) => (name, true),
+                None => (sort.as_str(), false),
+            };
+            match TableColumn::from_name(name) {
+                Some(column) => tab.sort = Some((column, desc)),
+                None => bail!("Unknown sort `{}` in view `{}`", sort, view.name),
+            }
+        }
+        Ok(tab)
+    }
+
+    fn matches(&self, issue: &Issue) -> bool {
+        let Some(ref query) = self.query else {
+            return true;
+        };
+        issue.id.to_lowercase().contains(query)
+            || issue.title.to_lowercase().contains(query)
+            || issue.labels.iter().any(|l| l.to_lowercase().contains(query))
+            || issue
+                .assignee
+                .as_ref()
+                .is_some_and(|a| a.to_lowercase().contains(query))
+    }
+}
+
+/// Which lines the burndown chart plots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BurnMode {
+    /// Remaining open issues, projected down to zero.
+    Down,
+    /// Scope and closed issues, projected up to the scope.
+    Up,
+}
+
+/// Live board filter: cards that don't match the query are hidden until
+/// the filter is cleared.
+#[derive(Debug, Clone, Default)]
+pub struct BoardFilter {
+    pub input: String,
+    /// Last input that parsed; kept while the input has a syntax error
+    pub query: Query,
+    pub error: Option<QueryError>,
+    /// How bare words match: fuzzy, unless the filter came from a saved search
+    pub mode: SearchMode,
+    /// The query's bare words, in `mode`
+    matcher: Option<TextMatcher>,
+    phrases: Vec<TextMatcher>,
+    /// IDs of the issues the query matches, looked up through the search
+    /// index when the query or the issues change; None for an empty query
+    shown: Option<HashSet<String>>,
+}
+
+impl BoardFilter {
+    fn set_input(&mut self, input: String, issues: &[Issue], index: &SearchIndex) {
+        let parsed = Query::parse(&input, self.mode).and_then(|query| {
+            // An invalid regex marks the whole input
+            let matcher = query
+                .bare_text()
+                .map(|text| TextMatcher::new(self.mode, &text))
+                .transpose()
+                .map_err(|message| QueryError {
+                    message,
+                    start: 0,
+                    end: input.chars().count(),
+                })?;
+            Ok((query, matcher))
+        });
+        match parsed {
+            Ok((query, matcher)) => {
+                self.matcher = matcher;
+                self.phrases = query.phrase_matchers();
+                self.query = query;
+                self.error = None;
+                self.refresh(issues, index);
+            }
+            Err(e) => self.error = Some(e),
+        }
+        self.input = input;
+    }
+
+    /// Finds the matching issues again, as after a reload.
+    fn refresh(&mut self, issues: &[Issue], index: &SearchIndex) {
+        self.shown = (!self.query.terms.is_empty()).then(|| {
+            index
+                .search(issues, &self.query, self.matcher.as_ref())
+                .into_iter()
+                .map(|pos| issues[pos].id.clone())
+                .collect()
+        });
+    }
+
+    /// Whether the issue's card stays on the board.
+    pub fn shows(&self, issue: &Issue) -> bool {
+        self.shown.as_ref().is_none_or(|shown| shown.contains(&issue.id))
+    }
+
+    /// Title characters to highlight, or None when the issue is filtered out.
+    pub fn title_matches(&self, issue: &Issue) -> Option<Vec<usize>> {
+        self.shows(issue)
+            .then(|| match_indices(&issue.title, self.matcher.as_ref(), &self.phrases))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    Title,
+    Description,
+    Labels,
+    Assignee,
+}
+
+/// Where in one field a search matched.
+#[derive(Debug, Clone)]
+pub struct FieldMatch {
+    pub field: SearchField,
+    /// Char indices into the field's text (labels joined with ", ")
+    pub indices: Vec<usize>,
 }
 
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub issue_id: String,
-    pub score: i64,
-    pub title_match_indices: Vec<usize>,
+    /// Fields the query matched in, in `SearchField` order. Worked out when
+    /// the result is first shown, as most never are
+    matches: OnceCell<Vec<FieldMatch>>,
+}
+
+/// Char indices matched in one field, empty if it didn't match.
+pub fn field_indices(matches: &[FieldMatch], field: SearchField) -> &[usize] {
+    matches
+        .iter()
+        .find(|m| m.field == field)
+        .map_or(&[], |m| m.indices.as_slice())
 }
 
 pub struct App {
     pub db: BeadsClient,
+    pub config: Config,
     pub label_filter: Option<String>,
     pub issues: Vec<Issue>,
+    /// Built from `issues` on each load
+    pub search_index: SearchIndex,
+    /// Label filter `issues` were loaded with; None before the first load
+    loaded_label: Option<Option<String>>,
+    /// When the load of `issues` started
+    loaded_at: DateTime<Local>,
     pub current_view: View,
     pub selected_column: Column,
     pub selected_index: usize,
+    /// Launch view first, then the configured views
+    pub tabs: Vec<BoardTab>,
+    pub current_tab: usize,
+    pub card_density: CardDensity,
+    /// Live filter narrowing the board's cards, while it's being typed
+    pub board_filter: Option<BoardFilter>,
+    /// Show the selected issue's details beside the board
+    pub preview: bool,
+    /// Preview pane width, in percent of the board
+    pub preview_width: u16,
     pub should_quit: bool,
+    /// `?` help overlay over the current view
+    pub show_help: bool,
+    pub help_scroll: u16,
+    /// Command palette over the current view, when open
+    pub palette: Option<PaletteState>,
+    /// Jump-to-issue prompt over the current view, when open
+    pub jump: Option<JumpState>,
+    // Activity feed
+    /// Changes seen between reloads this session, oldest first
+    pub activity: Vec<Activity>,
+    pub show_activity: bool,
+    /// Whether the board's keys go to the feed
+    pub activity_focus: bool,
+    /// Index into the feed as shown, newest first
+    pub activity_selected: usize,
     // Detail scrolling
     pub detail_scroll: u16,
     pub detail_scroll_max: u16,
     pub detail_viewport_height: u16,
+    /// View to go back to when leaving the detail view
+    pub detail_return: View,
+    /// Issue mentions in the shown description, from the last render
+    pub detail_links: Vec<DescriptionLink>,
+    /// Index into `detail_links` of the mention Tab moved to
+    pub detail_link: Option<usize>,
+    /// Issues left by following mentions, to go back to on Esc, each with the
+    /// live board filter following it cleared
+    detail_trail: Vec<(String, Option<BoardFilter>)>,
+    // Epics
+    pub epic_selected: usize,
+    pub epic_collapsed: HashSet<String>,
+    // Dependency graph
+    pub graph_focus: Option<String>,
+    pub graph_selected: Option<String>,
+    pub graph_depth: usize,
+    pub graph_whole: bool,
+    /// Layout of the graph view, built when first drawn and dropped whenever
+    /// the issues, focus, depth or whole-graph toggle change
+    graph_cache: OnceCell<DagLayout>,
+    // Ready work
+    pub ready_selected: usize,
+    // Blocked work
+    pub blocked_selected: usize,
+    // Table
+    pub table_selected: usize,
+    /// Visible columns, in display order
+    pub table_columns: Vec<TableColumn>,
+    /// Header column under the cursor, as an index into `table_columns`
+    pub table_cursor: usize,
+    pub table_sort: TableColumn,
+    pub table_sort_desc: bool,
+    // Burndown
+    /// Epic whose descendants are charted; None charts the loaded (label) set
+    pub burndown_epic: Option<String>,
+    pub burndown_mode: BurnMode,
+    // Cumulative flow
+    /// Index into FLOW_SPANS
+    pub flow_span: usize,
+    /// Days between the end of the shown range and today
+    pub flow_offset: usize,
     // Search
     pub search_query: String,
+    /// Syntax error in `search_query`, shown under the input
+    pub search_error: Option<QueryError>,
+    /// How bare words match; kept for the session
+    pub search_mode: SearchMode,
+    /// Bare-word and phrase matchers behind `search_results`, for highlighting
+    search_matcher: Option<TextMatcher>,
+    search_phrases: Vec<TextMatcher>,
+    pub search_history: SearchHistory,
+    /// Index into the recent queries while recalling them
+    pub search_history_pos: Option<usize>,
+    /// What was typed before recalling history, restored past the newest
+    search_draft: RecentSearch,
+    /// Name being typed to save the query under
+    pub search_naming: Option<String>,
     pub search_results: Vec<SearchResult>,
     pub search_selected: usize,
+    // Mouse
+    pub board_layout: BoardLayout,
+    pub search_layout: SearchLayout,
+    pub drag: Option<DragState>,
+    last_click: Option<(Instant, String)>,
     // Error status
     pub status_message: Option<String>,
 }
 
 impl App {
-    pub fn new(db: BeadsClient, label_filter: Option<String>) -> Result<Self> {
+    pub fn new(db: BeadsClient, label_filter: Option<String>, config: Config) -> Result<Self> {
+        let graph_depth = config
+            .graph_depth
+            .unwrap_or(DEFAULT_GRAPH_DEPTH)
+            .clamp(1, MAX_GRAPH_DEPTH);
+        // Past searches aren't worth refusing to start over
+        let (search_history, history_error) = match SearchHistory::load(db.beads_dir()) {
+            Ok(history) => (history, None),
+            Err(e) => (
+                SearchHistory::default(),
+                Some(format!("Search history not loaded: {e:#}")),
+            ),
+        };
+        let launch_name = label_filter.clone().unwrap_or_else(|| "All".to_string());
+        let mut tabs = vec![BoardTab::new(launch_name, label_filter.clone())];
+        // Likewise a mistake in one view; the rest still get their tabs
+        let mut view_error = None;
+        for view in &config.views {
+            match BoardTab::from_config(view) {
+                Ok(tab) => tabs.push(tab),
+                Err(e) => view_error = view_error.or(Some(format!("View skipped: {e}"))),
+            }
+        }
         let mut app = App {
             db,
+            config,
             label_filter,
             issues: Vec::new(),
+            search_index: SearchIndex::default(),
+            loaded_label: None,
+            loaded_at: Local::now(),
             current_view: View::Board,
             selected_column: Column::Open,
             selected_index: 0,
+            tabs,
+            current_tab: 0,
+            card_density: CardDensity::Compact,
+            board_filter: None,
+            preview: false,
+            preview_width: DEFAULT_PREVIEW_WIDTH,
             should_quit: false,
+            show_help: false,
+            help_scroll: 0,
+            palette: None,
+            jump: None,
+            activity: Vec::new(),
+            show_activity: false,
+            activity_focus: false,
+            activity_selected: 0,
             detail_scroll: 0,
             detail_scroll_max: 0,
             detail_viewport_height: 0,
+            detail_return: View::Board,
+            detail_links: Vec::new(),
+            detail_link: None,
+            detail_trail: Vec::new(),
+            epic_selected: 0,
+            epic_collapsed: HashSet::new(),
+            graph_focus: None,
+            graph_selected: None,
+            graph_depth,
+            graph_whole: false,
+            graph_cache: OnceCell::new(),
+            ready_selected: 0,
+            blocked_selected: 0,
+            table_selected: 0,
+            table_columns: TableColumn::ALL.to_vec(),
+            table_cursor: 0,
+            table_sort: TableColumn::Priority,
+            table_sort_desc: false,
+            burndown_epic: None,
+            burndown_mode: BurnMode::Down,
+            flow_span: DEFAULT_FLOW_SPAN,
+            flow_offset: 0,
             search_query: String::new(),
+            search_error: None,
+            search_mode: SearchMode::default(),
+            search_matcher: None,
+            search_phrases: Vec::new(),
+            search_history,
+            search_history_pos: None,
+            search_draft: RecentSearch::default(),
+            search_naming: None,
             search_results: Vec::new(),
             search_selected: 0,
+            board_layout: BoardLayout::default(),
+            search_layout: SearchLayout::default(),
+            drag: None,
+            last_click: None,
             status_message: None,
         };
         app.reload_issues()?;
+        if let Some(error) = view_error.or(history_error) {
+            app.status_message = Some(error);
+        }
         Ok(app)
     }
 
     pub fn reload_issues(&mut self) -> Result<()> {
-        self.issues = self.db.load_issues(self.label_filter.as_deref())?;
+        let started = Local::now();
+        let loaded = self
+            .db
+            .load_issues(self.label_filter.as_deref(), self.config.issue_limit)?;
+        let issues = loaded.issues;
+        // Another label loads a different set of issues, which isn't activity
+        if self.loaded_label.as_ref() == Some(&self.label_filter) {
+            let changes = activity::changes(
+                &self.issues,
+                &issues,
+                self.loaded_at,
+                self.label_filter.as_deref(),
+                Local::now(),
+            );
+            // Stay on the selected change unless following the newest
+            if self.activity_selected > 0 {
+                self.activity_selected += changes.len();
+            }
+            self.activity.extend(changes);
+        }
+        self.loaded_label = Some(self.label_filter.clone());
+        self.loaded_at = started;
+        self.search_index = SearchIndex::new(&issues);
+        self.issues = issues;
+        self.graph_cache.take();
+        if let Some(ref mut filter) = self.board_filter {
+            filter.refresh(&self.issues, &self.search_index);
+        }
         self.clamp_selection();
+        let ready_count = self.ready_issues().len();
+        self.ready_selected = self.ready_selected.min(ready_count.saturating_sub(1));
+        let blocked_count = self.blocked_issues().len();
+        self.blocked_selected = self.blocked_selected.min(blocked_count.saturating_sub(1));
+        self.table_selected = self.table_selected.min(self.issues.len().saturating_sub(1));
         // Clamp detail scroll in case content changed
         self.detail_scroll = self.detail_scroll.min(self.detail_scroll_max);
         // Refresh search results if in search view
         if self.current_view == View::Search {
             self.update_search_results();
         }
+        self.status_message = loaded.warning;
         Ok(())
     }
 
     pub fn try_reload_issues(&mut self) {
-        match self.reload_issues() {
-            Ok(()) => self.status_message = None,
-            Err(e) => self.status_message = Some(format!("Refresh failed: {e}")),
+        if let Err(e) = self.reload_issues() {
+            self.status_message = Some(format!("Refresh failed: {e}"));
+        }
+    }
+
+    pub fn tab(&self) -> &BoardTab {
+        &self.tabs[self.current_tab]
+    }
+
+    /// Switches board tab, keeping each tab's selection. Reloads when the tab
+    /// uses a different label filter.
+    pub fn switch_tab(&mut self, index: usize) {
+        if index >= self.tabs.len() || index == self.current_tab {
+            return;
+        }
+        let current = &mut self.tabs[self.current_tab];
+        current.selected_column = self.selected_column;
+        current.selected_index = self.selected_index;
+
+        self.current_tab = index;
+        let tab = &self.tabs[index];
+        self.selected_column = tab.selected_column;
+        self.selected_index = tab.selected_index;
+        if tab.label != self.label_filter {
+            self.label_filter = tab.label.clone();
+            self.try_reload_issues();
+        } else {
+            self.clamp_selection();
         }
     }
 
+    /// Issues in a board column, filtered and ordered by the current tab.
     pub fn get_column_issues(&self, column: Column) -> Vec<&Issue> {
+        let mut issues = self.column_issues_unfiltered(column);
+        if let Some(ref filter) = self.board_filter {
+            issues.retain(|i| filter.shows(i));
+        }
+        issues
+    }
+
+    /// A column's issues in the current tab, ignoring the live board filter.
+    pub fn column_issues_unfiltered(&self, column: Column) -> Vec<&Issue> {
         let statuses = column.status();
-        self.issues
+        let tab = self.tab();
+        let mut issues: Vec<&Issue> = self
+            .issues
+            .iter()
+            .filter(|i| statuses.contains(&i.status) && tab.matches(i))
+            .collect();
+        if let Some((sort, desc)) = tab.sort {
+            issues.sort_by(|a, b| {
+                let ordering = sort.compare(a, b);
+                if desc {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        issues
+    }
+
+    /// The visible column after (or before) the selected one, wrapping.
+    fn adjacent_column(&self, forward: bool) -> Column {
+        let columns = &self.tab().columns;
+        let pos = columns
             .iter()
-            .filter(|i| statuses.contains(&i.status))
+            .position(|&c| c == self.selected_column)
+            .unwrap_or(0);
+        let next = if forward {
+            (pos + 1) % columns.len()
+        } else {
+            (pos + columns.len() - 1) % columns.len()
+        };
+        columns[next]
+    }
+
+    /// Configured WIP limit for a column, if any.
+    pub fn column_limit(&self, column: Column) -> Option<usize> {
+        let limits = &self.config.wip_limits;
+        match column {
+            Column::Open => limits.open,
+            Column::InProgress => limits.in_progress,
+            Column::Done => limits.done,
+        }
+    }
+
+    /// Assignees with more in-progress issues than the per-assignee limit,
+    /// as (assignee, count).
+    pub fn assignees_over_limit(&self) -> Vec<(String, usize)> {
+        let Some(limit) = self.config.wip_limits.per_assignee else {
+            return Vec::new();
+        };
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for issue in self.get_column_issues(Column::InProgress) {
+            if let Some(ref assignee) = issue.assignee {
+                *counts.entry(assignee.as_str()).or_default() += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .filter(|&(_, count)| count > limit)
+            .map(|(assignee, count)| (assignee.to_string(), count))
             .collect()
     }
 
+    /// Whether the board's keys go to the activity feed: it has focus and
+    /// the last frame had room to draw it.
+    pub fn activity_has_keys(&self) -> bool {
+        self.activity_focus && self.board_layout.activity.is_some()
+    }
+
+    /// The loaded issue with this ID.
+    pub fn issue(&self, id: &str) -> Option<&Issue> {
+        self.search_index.position(id).map(|pos| &self.issues[pos])
+    }
+
     pub fn selected_issue(&self) -> Option<&Issue> {
         let issues = self.get_column_issues(self.selected_column);
         issues.get(self.selected_index).copied()
     }
 
+    /// Selects the issue on the board, switching to its column. Returns false if
+    /// the issue isn't loaded or the tab or live filter hides it.
+    pub fn select_issue_on_board(&mut self, issue_id: &str) -> bool {
+        let Some(issue) = self.issue(issue_id) else {
+            return false;
+        };
+        let col = Column::for_status(issue.status);
+        let Some(idx) = self
+            .get_column_issues(col)
+            .iter()
+            .position(|i| i.id == issue_id)
+        else {
+            return false;
+        };
+        self.selected_column = col;
+        self.selected_index = idx;
+        true
+    }
+
+    /// Selects the issue on the board like `select_issue_on_board`, first
+    /// clearing the live filter or switching to another tab of the same label
+    /// if either hides it. Returns false if no tab shows it.
+    fn reveal_on_board(&mut self, issue_id: &str) -> bool {
+        let Some(issue) = self.issue(issue_id) else {
+            return false;
+        };
+        let Some(tab) = self.reveal_tab(issue) else {
+            return false;
+        };
+        let filtered_out = self
+            .board_filter
+            .as_ref()
+            .is_some_and(|filter| !filter.shows(issue));
+        self.switch_tab(tab);
+        if filtered_out {
+            self.board_filter = None;
+        }
+        self.select_issue_on_board(issue_id)
+    }
+
+    /// Whether `reveal_on_board` can show the issue.
+    pub fn can_reveal(&self, issue_id: &str) -> bool {
+        self.issue(issue_id)
+            .is_some_and(|issue| self.reveal_tab(issue).is_some())
+    }
+
+    /// The tab to show an issue on, the current one if it can: a tab of the
+    /// same label showing the issue's column and passing its query.
+    fn reveal_tab(&self, issue: &Issue) -> Option<usize> {
+        let column = Column::for_status(issue.status);
+        let shows = |tab: &BoardTab| {
+            tab.label == self.label_filter && tab.columns.contains(&column) && tab.matches(issue)
+        };
+        if shows(self.tab()) {
+            Some(self.current_tab)
+        } else {
+            self.tabs.iter().position(shows)
+        }
+    }
+
+    fn open_detail(&mut self) {
+        if self.selected_issue().is_some() {
+            self.detail_scroll = 0;
+            self.detail_link = None;
+            self.detail_trail.clear();
+            self.detail_return = View::Board;
+            self.current_view = View::Detail;
+        }
+    }
+
+    /// Opens an issue's detail view from another view, returning there on Esc.
+    fn open_detail_from(&mut self, issue_id: &str, return_view: View) {
+        if self.reveal_on_board(issue_id) {
+            self.open_detail();
+            self.detail_return = return_view;
+        }
+    }
+
+    /// Open issues with no open blockers, highest priority then oldest first,
+    /// as `bd ready` would pick them.
+    pub fn ready_issues(&self) -> Vec<&Issue> {
+        let graph = IssueGraph::new(&self.issues);
+        let mut ready: Vec<&Issue> = self.issues.iter().filter(|i| graph.is_ready(i)).collect();
+        ready.sort_by(|a, b| {
+            a.priority
+                .cmp(&b.priority)
+                .then_with(|| parse_timestamp(&a.created_at).cmp(&parse_timestamp(&b.created_at)))
+        });
+        ready
+    }
+
+    /// Issues blocked by open issues (or marked blocked), by priority.
+    pub fn blocked_issues(&self) -> Vec<&Issue> {
+        let mut blocked: Vec<&Issue> = self.issues.iter().filter(|i| i.is_blocked()).collect();
+        blocked.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.id.cmp(&b.id)));
+        blocked
+    }
+
+    /// All issues, ordered by the table's sort column (ties broken by ID).
+    pub fn table_issues(&self) -> Vec<&Issue> {
+        let mut issues: Vec<&Issue> = self.issues.iter().collect();
+        issues.sort_by(|a, b| {
+            let ordering = self.table_sort.compare(a, b);
+            let ordering = if self.table_sort_desc {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            ordering.then_with(|| a.id.cmp(&b.id))
+        });
+        issues
+    }
+
+    /// Name and issues of the burndown chart's scope: the selected epic's
+    /// descendants, or everything loaded under the label filter.
+    pub fn burndown_scope(&self) -> (String, Vec<&Issue>) {
+        if let Some(ref epic) = self.burndown_epic {
+            let graph = IssueGraph::new(&self.issues);
+            let title = graph
+                .issue(epic)
+                .map(|i| format!("{} {}", i.id, i.title))
+                .unwrap_or_else(|| epic.clone());
+            return (format!("epic {}", title), graph.descendants(epic));
+        }
+        let name = self
+            .label_filter
+            .as_ref()
+            .map(|l| format!("label {}", l))
+            .unwrap_or_else(|| "all issues".to_string());
+        (name, self.issues.iter().collect())
+    }
+
+    /// First and last day shown in the cumulative flow view.
+    pub fn flow_range(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let first = stats::first_created(&self.issues)
+            .unwrap_or(today)
+            .min(today);
+        match FLOW_SPANS[self.flow_span] {
+            Some(days) => {
+                let end = today - Days::new(self.flow_offset as u64);
+                (end - Days::new(days as u64 - 1), end)
+            }
+            None => (first, today),
+        }
+    }
+
+    /// How far the flow range can pan back before it starts before the
+    /// first issue was created.
+    fn max_flow_offset(&self, today: NaiveDate) -> usize {
+        let Some(days) = FLOW_SPANS[self.flow_span] else {
+            return 0;
+        };
+        let first = stats::first_created(&self.issues).unwrap_or(today);
+        let history = (today - first).num_days().max(0) as usize;
+        history.saturating_sub(days - 1)
+    }
+
+    /// Visible rows of the epic tree.
+    pub fn epic_rows(&self) -> Vec<TreeRow> {
+        IssueGraph::new(&self.issues).epic_tree(&self.epic_collapsed)
+    }
+
+    fn select_prev(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    fn select_next(&mut self) {
+        let count = self.get_column_issues(self.selected_column).len();
+        if self.selected_index + 1 < count {
+            self.selected_index += 1;
+        }
+    }
+
+    /// Moves an issue to another column by updating its status through bd.
+    fn move_issue(&mut self, issue_id: &str, column: Column) {
+        if let Err(e) = self.db.update_status(issue_id, column.target_status()) {
+            self.status_message = Some(format!("Move failed: {e}"));
+            return;
+        }
+        self.try_reload_issues();
+        self.select_issue_on_board(issue_id);
+    }
+
     pub fn handle_key(&mut self, key: KeyEvent) -> Result<()> {
         if key.kind != KeyEventKind::Press {
             return Ok(());
         }
 
+        if self.show_help {
+            // Scroll the overlay; any other key closes it
+            match key.code {
+                KeyCode::Down | KeyCode::Char('j') => self.help_scroll += 1,
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.help_scroll = self.help_scroll.saturating_sub(1)
+                }
+                _ => self.show_help = false,
+            }
+            return Ok(());
+        }
+
+        if self.palette.is_some() {
+            return self.handle_palette_key(key);
+        }
+        if self.jump.is_some() {
+            self.handle_jump_key(key);
+            return Ok(());
+        }
+
+        let Some(action) = keymap::action_for(keymap::current_bindings(self), &key) else {
+            return Ok(());
+        };
+        match action {
+            Action::Help => {
+                self.show_help = true;
+                self.help_scroll = 0;
+                return Ok(());
+            }
+            Action::OpenPalette => {
+                self.palette = Some(PaletteState::default());
+                return Ok(());
+            }
+            Action::JumpToIssue => {
+                self.jump = Some(JumpState::default());
+                return Ok(());
+            }
+            _ => {}
+        }
+
         match self.current_view {
-            View::Board => self.handle_board_key(key),
-            View::Detail => self.handle_detail_key(key),
-            View::Search => self.handle_search_key(key),
+            View::Board if self.board_filter.is_some() => self.handle_filter_key(action, key),
+            View::Board if self.activity_has_keys() => self.handle_activity_key(action),
+            View::Board => self.handle_board_key(action, key),
+            View::Detail => self.handle_detail_key(action),
+            View::Search => self.handle_search_key(action, key),
+            View::Epics => self.handle_epics_key(action),
+            View::Graph => self.handle_graph_key(action),
+            View::Ready => self.handle_ready_key(action),
+            View::Blocked => self.handle_blocked_key(action),
+            View::Table => self.handle_table_key(action, key),
+            View::Dashboard => self.handle_dashboard_key(action),
+            View::Burndown => self.handle_burndown_key(action),
+            View::Flow => self.handle_flow_key(action),
         }
     }
 
-    fn handle_board_key(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => {
+    fn handle_board_key(&mut self, action: Action, key: KeyEvent) -> Result<()> {
+        match action {
+            Action::Quit => {
                 self.should_quit = true;
             }
-            KeyCode::Char('r') => {
+            Action::Refresh => {
                 self.try_reload_issues();
             }
-            KeyCode::Left | KeyCode::Char('h') => {
-                self.selected_column = self.selected_column.prev();
+            Action::Left => {
+                self.selected_column = self.adjacent_column(false);
                 self.selected_index = 0;
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                self.selected_column = self.selected_column.next();
+            Action::Right => {
+                self.selected_column = self.adjacent_column(true);
                 self.selected_index = 0;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.selected_index > 0 {
-                    self.selected_index -= 1;
-                }
+            Action::Up => {
+                self.select_prev();
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                let count = self.get_column_issues(self.selected_column).len();
-                if count > 0 && self.selected_index < count - 1 {
-                    self.selected_index += 1;
-                }
+            Action::Down => {
+                self.select_next();
             }
-            KeyCode::Enter => {
-                if self.selected_issue().is_some() {
-                    self.detail_scroll = 0;
-                    self.current_view = View::Detail;
+            Action::Open => {
+                self.open_detail();
+            }
+            Action::NextTab => {
+                self.switch_tab((self.current_tab + 1) % self.tabs.len());
+            }
+            Action::PrevTab => {
+                self.switch_tab((self.current_tab + self.tabs.len() - 1) % self.tabs.len());
+            }
+            Action::SwitchTab => {
+                if let KeyCode::Char(c @ '1'..='9') = key.code {
+                    self.switch_tab(c as usize - '1' as usize);
                 }
             }
-            KeyCode::Char('/') => {
-                self.search_query.clear();
-                self.search_selected = 0;
-                self.update_search_results();
-                self.current_view = View::Search;
+            Action::ToggleDensity => {
+                self.card_density = self.card_density.toggle();
+            }
+            Action::TogglePreview => {
+                self.preview = !self.preview;
+            }
+            Action::NarrowPreview if self.preview => {
+                self.preview_width = self
+                    .preview_width
+                    .saturating_sub(PREVIEW_WIDTH_STEP)
+                    .max(MIN_PREVIEW_WIDTH);
+            }
+            Action::WidenPreview if self.preview => {
+                self.preview_width = (self.preview_width + PREVIEW_WIDTH_STEP).min(MAX_PREVIEW_WIDTH);
             }
+            Action::ShowActivity => {
+                self.show_activity = true;
+                self.activity_focus = true;
+            }
+            Action::Filter => {
+                self.board_filter = Some(BoardFilter::default());
+            }
+            Action::ShowEpics => self.show_view(View::Epics),
+            Action::ShowReady => self.show_view(View::Ready),
+            Action::ShowBlocked => self.show_view(View::Blocked),
+            Action::ShowTable => self.show_view(View::Table),
+            Action::ShowDashboard => self.show_view(View::Dashboard),
+            Action::ShowBurndown => self.show_view(View::Burndown),
+            Action::ShowFlow => self.show_view(View::Flow),
+            Action::ShowGraph => self.show_view(View::Graph),
+            Action::ShowSearch => self.show_view(View::Search),
             _ => {}
         }
         Ok(())
     }
 
-    fn handle_detail_key(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Esc | KeyCode::Char('q') => {
-                self.detail_scroll = 0;
-                self.current_view = View::Board;
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.detail_scroll < self.detail_scroll_max {
-                    self.detail_scroll += 1;
+    fn handle_filter_key(&mut self, action: Action, key: KeyEvent) -> Result<()> {
+        let Some(ref filter) = self.board_filter else {
+            return Ok(());
+        };
+        let mut input = filter.input.clone();
+        match action {
+            Action::Back => {
+                // Back to the whole board, still on the selected issue
+                let selected = self.selected_issue().map(|i| i.id.clone());
+                self.board_filter = None;
+                match selected {
+                    Some(issue_id) => {
+                        self.select_issue_on_board(&issue_id);
+                    }
+                    None => self.clamp_selection(),
                 }
+                return Ok(());
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+            Action::Left => {
+                self.selected_column = self.adjacent_column(false);
+                self.selected_index = 0;
+                return Ok(());
             }
-            KeyCode::Char('g') | KeyCode::Home => {
-                self.detail_scroll = 0;
+            Action::Right => {
+                self.selected_column = self.adjacent_column(true);
+                self.selected_index = 0;
+                return Ok(());
             }
-            KeyCode::Char('G') | KeyCode::End => {
-                self.detail_scroll = self.detail_scroll_max;
+            Action::Up => {
+                self.select_prev();
+                return Ok(());
             }
-            KeyCode::PageDown => {
-                let jump = self.detail_viewport_height.max(1);
-                self.detail_scroll = (self.detail_scroll + jump).min(self.detail_scroll_max);
+            Action::Down => {
+                self.select_next();
+                return Ok(());
             }
-            KeyCode::PageUp => {
-                let jump = self.detail_viewport_height.max(1);
-                self.detail_scroll = self.detail_scroll.saturating_sub(jump);
+            Action::Open => {
+                self.open_detail();
+                return Ok(());
             }
-            _ => {}
+            Action::ClearQuery => input.clear(),
+            Action::DeleteChar => {
+                input.pop();
+            }
+            Action::InsertChar => {
+                if let KeyCode::Char(c) = key.code {
+                    input.push(c);
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        // Stay on the selected card while it still matches
+        let selected = self.selected_issue().map(|i| i.id.clone());
+        if let Some(ref mut filter) = self.board_filter {
+            filter.set_input(input, &self.issues, &self.search_index);
+        }
+        let position = selected.and_then(|id| {
+            self.get_column_issues(self.selected_column)
+                .iter()
+                .position(|i| i.id == id)
+        });
+        match position {
+            Some(idx) => self.selected_index = idx,
+            None => self.clamp_selection(),
         }
         Ok(())
     }
 
-    fn handle_search_key(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Esc => {
-                self.current_view = View::Board;
+    fn handle_activity_key(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Back => {
+                self.activity_focus = false;
             }
-            KeyCode::Enter => {
-                if let Some(result) = self.search_results.get(self.search_selected) {
-                    let issue_id = result.issue_id.clone();
-                    // Find the issue and navigate to its position on the board
-                    if let Some(issue) = self.issues.iter().find(|i| i.id == issue_id) {
-                        let col = match issue.status {
-                            Status::Open | Status::Blocked | Status::Deferred | Status::Unknown => Column::Open,
-                            Status::InProgress => Column::InProgress,
-                            Status::Closed => Column::Done,
-                        };
-                        // Find index within that column
-                        let col_issues = self.get_column_issues(col);
-                        let idx = col_issues
-                            .iter()
-                            .position(|i| i.id == issue_id)
-                            .unwrap_or(0);
-                        self.selected_column = col;
-                        self.selected_index = idx;
-                        self.detail_scroll = 0;
-                        self.current_view = View::Detail;
-                    }
-                }
+            Action::ShowActivity => {
+                self.show_activity = false;
+                self.activity_focus = false;
             }
-            KeyCode::Up => {
-                if self.search_selected > 0 {
-                    self.search_selected -= 1;
-                }
+            Action::Up => {
+                self.activity_selected = self.activity_selected.saturating_sub(1);
             }
-            KeyCode::Down => {
-                if !self.search_results.is_empty()
-                    && self.search_selected < self.search_results.len() - 1
-                {
-                    self.search_selected += 1;
+            Action::Down => {
+                self.activity_selected =
+                    (self.activity_selected + 1).min(self.activity.len().saturating_sub(1));
+            }
+            Action::Open => {
+                let Some(entry) = self.activity.iter().rev().nth(self.activity_selected) else {
+                    return Ok(());
+                };
+                let issue_id = entry.issue_id.clone();
+                if self.reveal_on_board(&issue_id) {
+                    self.activity_focus = false;
+                } else if self.issue(&issue_id).is_some() {
+                    self.status_message = Some(format!("{} isn't shown by any board view", issue_id));
+                } else {
+                    self.status_message = Some(format!("{} is no longer loaded", issue_id));
                 }
             }
-            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Switches to a view as the board's keys do, starting it fresh.
+    fn show_view(&mut self, view: View) {
+        match view {
+            View::Ready => self.ready_selected = 0,
+            View::Blocked => self.blocked_selected = 0,
+            View::Burndown => self.burndown_epic = None,
+            View::Graph => {
+                // Centred on the selected issue; nothing to show without one
+                let Some(issue_id) = self.selected_issue().map(|i| i.id.clone()) else {
+                    return;
+                };
+                self.graph_focus = Some(issue_id.clone());
+                self.graph_selected = Some(issue_id);
+                self.graph_cache.take();
+            }
+            View::Search => {
                 self.search_query.clear();
+                self.search_history_pos = None;
+                self.search_naming = None;
                 self.search_selected = 0;
                 self.update_search_results();
             }
-            KeyCode::Backspace => {
-                self.search_query.pop();
-                self.search_selected = 0;
-                self.update_search_results();
+            View::Detail => {
+                self.open_detail();
+                return;
             }
-            KeyCode::Char(c) => {
-                self.search_query.push(c);
-                self.search_selected = 0;
-                self.update_search_results();
+            _ => {}
+        }
+        self.current_view = view;
+    }
+
+    fn handle_palette_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(palette) = self.palette.as_mut() else {
+            return Ok(());
+        };
+        match keymap::palette_action(&key) {
+            Some(Action::Back) => {
+                self.palette = None;
+            }
+            Some(Action::Up) => {
+                palette.selected = palette.selected.saturating_sub(1);
+            }
+            Some(Action::Down) => {
+                palette.selected += 1;
+            }
+            Some(Action::ClearQuery) => {
+                palette.query.clear();
+                palette.selected = 0;
+            }
+            Some(Action::DeleteChar) => {
+                palette.query.pop();
+                palette.selected = 0;
+            }
+            Some(Action::InsertChar) => {
+                if let KeyCode::Char(c) = key.code {
+                    palette.query.push(c);
+                    palette.selected = 0;
+                }
+            }
+            Some(Action::Open) => {
+                let selected = palette.selected;
+                let matches = palette_matches(self);
+                let command = matches.into_iter().nth(selected).map(|e| e.command);
+                self.palette = None;
+                if let Some(command) = command {
+                    self.run_command(command);
+                }
+                return Ok(());
             }
             _ => {}
         }
+        // Keep the selection on a listed command
+        let count = palette_matches(self).len();
+        if let Some(palette) = self.palette.as_mut() {
+            palette.selected = palette.selected.min(count.saturating_sub(1));
+        }
         Ok(())
     }
 
-    pub fn update_search_results(&mut self) {
-        let matcher = SkimMatcherV2::default();
-
-        if self.search_query.is_empty() {
-            // Show all issues sorted by priority then updated_at
-            self.search_results = self
-                .issues
-                .iter()
-                .map(|issue| SearchResult {
-                    issue_id: issue.id.clone(),
-                    score: 0,
-                    title_match_indices: Vec::new(),
-                })
-                .collect();
-        } else {
-            let mut results: Vec<SearchResult> = self
-                .issues
-                .iter()
-                .filter_map(|issue| {
-                    let haystack = format!(
-                        "{} {} {} {} {}",
-                        issue.id,
-                        issue.title,
-                        issue.description.as_deref().unwrap_or(""),
-                        issue.assignee.as_deref().unwrap_or(""),
-                        issue.labels.join(" ")
-                    );
-                    let score = matcher.fuzzy_match(&haystack, &self.search_query)?;
-                    // Get title-specific match indices for highlighting
-                    let title_match_indices = matcher
-                        .fuzzy_indices(&issue.title, &self.search_query)
-                        .map(|(_, indices)| indices)
-                        .unwrap_or_default();
-                    Some(SearchResult {
-                        issue_id: issue.id.clone(),
-                        score,
-                        title_match_indices,
-                    })
-                })
-                .collect();
-            results.sort_by(|a, b| {
-                b.score.cmp(&a.score).then_with(|| {
-                    // Tiebreak by priority (lower = higher priority)
-                    let a_issue = self.issues.iter().find(|i| i.id == a.issue_id);
-                    let b_issue = self.issues.iter().find(|i| i.id == b.issue_id);
-                    let a_pri = a_issue.map(|i| i.priority.0).unwrap_or(255);
-                    let b_pri = b_issue.map(|i| i.priority.0).unwrap_or(255);
-                    a_pri.cmp(&b_pri).then_with(|| a.issue_id.cmp(&b.issue_id))
-                })
-            });
-            self.search_results = results;
+    fn handle_jump_key(&mut self, key: KeyEvent) {
+        let Some(jump) = self.jump.as_mut() else {
+            return;
+        };
+        match keymap::jump_action(&key) {
+            Some(Action::Back) => {
+                self.jump = None;
+            }
+            Some(Action::Up) => {
+                jump.selected = jump.selected.saturating_sub(1);
+            }
+            Some(Action::Down) => {
+                jump.selected += 1;
+            }
+            Some(Action::ClearQuery) => {
+                jump.input.clear();
+                jump.selected = 0;
+            }
+            Some(Action::DeleteChar) => {
+                jump.input.pop();
+                jump.selected = 0;
+            }
+            Some(Action::InsertChar) => {
+                if let KeyCode::Char(c) = key.code {
+                    jump.input.push(c);
+                    jump.selected = 0;
+                }
+            }
+            Some(Action::Complete) => {
+                if let Some(id) = completion(self) {
+                    self.jump = Some(JumpState {
+                        input: id,
+                        selected: 0,
+                    });
+                }
+            }
+            Some(action @ (Action::ShowOnBoard | Action::Open)) => {
+                let selected = jump.selected;
+                let Some(issue_id) = jump_matches(self).get(selected).map(|i| i.id.clone())
+                else {
+                    return;
+                };
+                self.jump = None;
+                self.jump_to(&issue_id, action == Action::Open);
+                return;
+            }
+            _ => {}
+        }
+        // Keep the selection on a listed issue
+        let count = jump_matches(self).len();
+        if let Some(jump) = self.jump.as_mut() {
+            jump.selected = jump.selected.min(count.saturating_sub(1));
+        }
+    }
+
+    /// Goes to an issue picked in the jump prompt: selects it on the board, or
+    /// opens its details, returning to the current view on Esc.
+    fn jump_to(&mut self, issue_id: &str, details: bool) {
+        if !self.reveal_on_board(issue_id) {
+            self.status_message = Some(format!("{} isn't shown by any board view", issue_id));
+            return;
+        }
+        if details {
+            let return_view = match self.current_view {
+                View::Detail => self.detail_return,
+                view => view,
+            };
+            self.open_detail();
+            self.detail_return = return_view;
+        } else {
+            self.activity_focus = false;
+            self.current_view = View::Board;
+        }
+    }
+
+    /// Runs a command chosen in the palette.
+    pub fn run_command(&mut self, command: Command) {
+        match command {
+            Command::Show(view) => self.show_view(view),
+            Command::Refresh => self.try_reload_issues(),
+            Command::FilterLabel(label) => {
+                // The label applies to the current board tab until switched away
+                self.tabs[self.current_tab].label = label.clone();
+                self.label_filter = label;
+                self.selected_index = 0;
+                self.try_reload_issues();
+            }
+            Command::SortBoard(column) => {
+                let tab = &mut self.tabs[self.current_tab];
+                tab.sort = column.map(|column| match tab.sort {
+                    Some((current, desc)) if current == column => (column, !desc),
+                    _ => (column, false),
+                });
+                self.selected_index = 0;
+            }
+            Command::SwitchTab(index) => self.switch_tab(index),
+            Command::TogglePreview => self.preview = !self.preview,
+            Command::Filter => {
+                self.current_view = View::Board;
+                self.board_filter = Some(BoardFilter::default());
+            }
+            Command::FilterBoard(query, mode) => {
+                let mut filter = BoardFilter {
+                    mode,
+                    ..BoardFilter::default()
+                };
+                filter.set_input(query, &self.issues, &self.search_index);
+                self.current_view = View::Board;
+                self.board_filter = Some(filter);
+                self.clamp_selection();
+            }
+            Command::ToggleActivity => {
+                self.show_activity = !self.show_activity;
+                self.activity_focus = self.show_activity;
+                self.current_view = View::Board;
+            }
+            Command::ToggleDensity => self.card_density = self.card_density.toggle(),
+            Command::JumpTo(issue_id) => {
+                self.current_view = View::Board;
+                self.open_detail_from(&issue_id, View::Board);
+            }
+            Command::Help => {
+                self.show_help = true;
+                self.help_scroll = 0;
+            }
+            Command::Quit => self.should_quit = true,
+        }
+    }
+
+    fn handle_detail_key(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Back => {
+                self.detail_scroll = 0;
+                self.detail_link = None;
+                // Back along the followed mentions, skipping issues since gone
+                while let Some((issue_id, filter)) = self.detail_trail.pop() {
+                    if !self.reveal_on_board(&issue_id) {
+                        continue;
+                    }
+                    // Put back a filter cleared on the way, if it still shows
+                    // the issue
+                    if let Some(mut filter) = filter.filter(|_| self.board_filter.is_none()) {
+                        filter.refresh(&self.issues, &self.search_index);
+                        if self.issue(&issue_id).is_some_and(|i| filter.shows(i)) {
+                            self.board_filter = Some(filter);
+                            self.select_issue_on_board(&issue_id);
+                        }
+                    }
+                    return Ok(());
+                }
+                self.current_view = self.detail_return;
+            }
+            Action::NextLink | Action::PrevLink => {
+                let count = self.detail_links.len();
+                if count == 0 {
+                    return Ok(());
+                }
+                let link = match (self.detail_link, action) {
+                    (None, Action::NextLink) => 0,
+                    (None, _) => count - 1,
+                    (Some(link), Action::NextLink) => (link + 1) % count,
+                    (Some(link), _) => (link + count - 1) % count,
+                };
+                self.detail_link = Some(link);
+                // Scroll the mention into view
+                let row = self.detail_links[link].row;
+                let height = self.detail_viewport_height.max(1);
+                if row < self.detail_scroll {
+                    self.detail_scroll = row;
+                } else if row >= self.detail_scroll + height {
+                    self.detail_scroll = (row + 1 - height).min(self.detail_scroll_max);
+                }
+            }
+            Action::Open => {
+                let Some(link) = self.detail_link.and_then(|i| self.detail_links.get(i)) else {
+                    return Ok(());
+                };
+                let issue_id = link.issue_id.clone();
+                let Some(current) = self.selected_issue().map(|i| i.id.clone()) else {
+                    return Ok(());
+                };
+                let filter = self.board_filter.clone();
+                if self.reveal_on_board(&issue_id) {
+                    let cleared = filter.filter(|_| self.board_filter.is_none());
+                    self.detail_trail.push((current, cleared));
+                    self.detail_scroll = 0;
+                    self.detail_link = None;
+                } else {
+                    self.status_message =
+                        Some(format!("{} isn't shown by any board view", issue_id));
+                }
+            }
+            Action::Down if self.detail_scroll < self.detail_scroll_max => {
+                self.detail_scroll += 1;
+            }
+            Action::Up => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+            }
+            Action::Top => {
+                self.detail_scroll = 0;
+            }
+            Action::Bottom => {
+                self.detail_scroll = self.detail_scroll_max;
+            }
+            Action::PageDown => {
+                let jump = self.detail_viewport_height.max(1);
+                self.detail_scroll = (self.detail_scroll + jump).min(self.detail_scroll_max);
+            }
+            Action::PageUp => {
+                let jump = self.detail_viewport_height.max(1);
+                self.detail_scroll = self.detail_scroll.saturating_sub(jump);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_search_key(&mut self, action: Action, key: KeyEvent) -> Result<()> {
+        if self.search_naming.is_some() {
+            self.handle_search_name_key(action, key);
+            return Ok(());
+        }
+        match action {
+            Action::Back => {
+                self.record_search();
+                self.current_view = View::Board;
+            }
+            Action::Open => {
+                self.record_search();
+                self.open_search_result();
+            }
+            Action::Up if self.search_selected > 0 => {
+                self.search_selected -= 1;
+            }
+            Action::Down if self.search_selected + 1 < self.search_results.len() => {
+                self.search_selected += 1;
+            }
+            Action::OlderQuery => {
+                let pos = self.search_history_pos.map_or(0, |pos| pos + 1);
+                if let Some(search) = self.search_history.recent.get(pos).cloned() {
+                    if self.search_history_pos.is_none() {
+                        self.search_draft = RecentSearch {
+                            query: std::mem::take(&mut self.search_query),
+                            mode: self.search_mode,
+                        };
+                    }
+                    self.search_query = search.query;
+                    self.search_mode = search.mode;
+                    self.search_history_pos = Some(pos);
+                    self.search_selected = 0;
+                    self.update_search_results();
+                }
+            }
+            Action::NewerQuery => {
+                match self.search_history_pos {
+                    Some(0) => {
+                        let draft = std::mem::take(&mut self.search_draft);
+                        self.search_query = draft.query;
+                        self.search_mode = draft.mode;
+                        self.search_history_pos = None;
+                    }
+                    Some(pos) => {
+                        let search = self.search_history.recent[pos - 1].clone();
+                        self.search_query = search.query;
+                        self.search_mode = search.mode;
+                        self.search_history_pos = Some(pos - 1);
+                    }
+                    None => return Ok(()),
+                }
+                self.search_selected = 0;
+                self.update_search_results();
+            }
+            Action::NextSearchMode => {
+                self.search_mode = self.search_mode.next();
+                self.search_selected = 0;
+                self.update_search_results();
+            }
+            Action::SaveSearch
+                if !self.search_query.trim().is_empty() && self.search_error.is_none() =>
+            {
+                self.search_naming = Some(String::new());
+            }
+            Action::ClearQuery => {
+                self.search_query.clear();
+                self.search_history_pos = None;
+                self.search_selected = 0;
+                self.update_search_results();
+            }
+            Action::DeleteChar => {
+                self.search_query.pop();
+                self.search_history_pos = None;
+                self.search_selected = 0;
+                self.update_search_results();
+            }
+            Action::InsertChar => {
+                if let KeyCode::Char(c) = key.code {
+                    self.search_query.push(c);
+                    self.search_history_pos = None;
+                    self.search_selected = 0;
+                    self.update_search_results();
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Keys while typing a name to save the search query under.
+    fn handle_search_name_key(&mut self, action: Action, key: KeyEvent) {
+        let Some(ref mut name) = self.search_naming else {
+            return;
+        };
+        match action {
+            Action::Back => {
+                self.search_naming = None;
+            }
+            Action::Open => {
+                let name = name.trim().to_string();
+                if name.is_empty() {
+                    return;
+                }
+                self.search_naming = None;
+                self.search_history
+                    .save_search(&name, &self.search_query, self.search_mode);
+                self.save_search_history();
+            }
+            Action::ClearQuery => name.clear(),
+            Action::DeleteChar => {
+                name.pop();
+            }
+            Action::InsertChar => {
+                if let KeyCode::Char(c) = key.code {
+                    name.push(c);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the current query to the recent searches, when there is one.
+    fn record_search(&mut self) {
+        let query = self.search_query.trim();
+        if query.is_empty() || self.search_error.is_some() {
+            return;
+        }
+        self.search_history.record(RecentSearch {
+            query: query.to_string(),
+            mode: self.search_mode,
+        });
+        self.save_search_history();
+    }
+
+    fn save_search_history(&mut self) {
+        if let Err(e) = self.search_history.save(self.db.beads_dir()) {
+            self.status_message = Some(format!("Saving searches failed: {e}"));
+        }
+    }
+
+    fn handle_epics_key(&mut self, action: Action) -> Result<()> {
+        let rows = self.epic_rows();
+        let selected = rows.get(self.epic_selected);
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Up => {
+                self.epic_selected = self.epic_selected.saturating_sub(1);
+            }
+            Action::Down => {
+                self.epic_selected = (self.epic_selected + 1).min(rows.len().saturating_sub(1));
+            }
+            Action::ToggleCollapse => {
+                if let Some(row) = selected.filter(|r| r.has_children) {
+                    if !self.epic_collapsed.remove(&row.issue_id) {
+                        self.epic_collapsed.insert(row.issue_id.clone());
+                    }
+                }
+            }
+            Action::Right => {
+                if let Some(row) = selected {
+                    self.epic_collapsed.remove(&row.issue_id);
+                }
+            }
+            Action::Left => {
+                if let Some(row) = selected.filter(|r| r.expanded) {
+                    self.epic_collapsed.insert(row.issue_id.clone());
+                } else if let Some(depth) = selected.map(|r| r.depth).filter(|&d| d > 0) {
+                    // Jump to the parent row
+                    if let Some(parent) = rows[..self.epic_selected]
+                        .iter()
+                        .rposition(|r| r.depth < depth)
+                    {
+                        self.epic_selected = parent;
+                    }
+                }
+            }
+            Action::Open => {
+                if let Some(row) = selected {
+                    let issue_id = row.issue_id.clone();
+                    self.open_detail_from(&issue_id, View::Epics);
+                }
+            }
+            Action::ShowBurndown => {
+                if let Some(row) = selected.filter(|r| r.has_children) {
+                    self.burndown_epic = Some(row.issue_id.clone());
+                    self.current_view = View::Burndown;
+                }
+            }
+            _ => {}
+        }
+        // Collapsing may have removed rows below the selection
+        let count = self.epic_rows().len();
+        self.epic_selected = self.epic_selected.min(count.saturating_sub(1));
+        Ok(())
+    }
+
+    /// The graph view's layout, built on first use after the graph changes.
+    pub fn graph_layout(&self) -> &DagLayout {
+        self.graph_cache.get_or_init(|| graph_layout(self))
+    }
+
+    fn handle_graph_key(&mut self, action: Action) -> Result<()> {
+        let direction = match action {
+            Action::Up => Some(GraphMove::Up),
+            Action::Down => Some(GraphMove::Down),
+            Action::Left => Some(GraphMove::Left),
+            Action::Right => Some(GraphMove::Right),
+            _ => None,
+        };
+        if let Some(direction) = direction {
+            let layout = self.graph_layout();
+            let next = self
+                .graph_selected
+                .as_deref()
+                .and_then(|id| layout.find(id))
+                .and_then(|n| layout.neighbor(n, direction))
+                .and_then(|n| layout.nodes[n].issue_id.clone());
+            if next.is_some() {
+                self.graph_selected = next;
+            }
+            return Ok(());
+        }
+
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Open => {
+                if let Some(issue_id) = self.graph_selected.clone() {
+                    self.open_detail_from(&issue_id, View::Graph);
+                }
+            }
+            Action::Focus => {
+                self.graph_focus = self.graph_selected.clone();
+                self.graph_whole = false;
+                self.graph_cache.take();
+            }
+            Action::Deeper => {
+                self.graph_depth = (self.graph_depth + 1).min(MAX_GRAPH_DEPTH);
+                self.graph_cache.take();
+            }
+            Action::Shallower => {
+                self.graph_depth = self.graph_depth.saturating_sub(1).max(1);
+                self.graph_cache.take();
+            }
+            Action::ToggleWholeGraph => {
+                self.graph_whole = !self.graph_whole;
+                self.graph_cache.take();
+            }
+            _ => {}
+        }
+
+        // The selection may have dropped out of a narrower graph
+        let layout = self.graph_layout();
+        if self
+            .graph_selected
+            .as_deref()
+            .and_then(|id| layout.find(id))
+            .is_none()
+        {
+            self.graph_selected = self.graph_focus.clone();
+        }
+        Ok(())
+    }
+
+    fn handle_ready_key(&mut self, action: Action) -> Result<()> {
+        let count = self.ready_issues().len();
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Refresh => {
+                self.try_reload_issues();
+            }
+            Action::Up => {
+                self.ready_selected = self.ready_selected.saturating_sub(1);
+            }
+            Action::Down => {
+                self.ready_selected = (self.ready_selected + 1).min(count.saturating_sub(1));
+            }
+            Action::Open => {
+                if let Some(issue_id) = self
+                    .ready_issues()
+                    .get(self.ready_selected)
+                    .map(|i| i.id.clone())
+                {
+                    self.open_detail_from(&issue_id, View::Ready);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_blocked_key(&mut self, action: Action) -> Result<()> {
+        let blocked: Vec<&Issue> = self.blocked_issues();
+        let count = blocked.len();
+        let selected = blocked.get(self.blocked_selected).copied();
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Refresh => {
+                self.try_reload_issues();
+            }
+            Action::Up => {
+                self.blocked_selected = self.blocked_selected.saturating_sub(1);
+            }
+            Action::Down => {
+                self.blocked_selected = (self.blocked_selected + 1).min(count.saturating_sub(1));
+            }
+            Action::Open => {
+                if let Some(issue_id) = selected.map(|i| i.id.clone()) {
+                    self.open_detail_from(&issue_id, View::Blocked);
+                }
+            }
+            Action::OpenRootCause => {
+                let root = selected.and_then(|issue| {
+                    IssueGraph::new(&self.issues)
+                        .blocker_chains(issue)
+                        .first()
+                        .and_then(|chain| chain.last())
+                        .map(|id| id.to_string())
+                });
+                if let Some(root) = root {
+                    self.open_detail_from(&root, View::Blocked);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_table_key(&mut self, action: Action, key: KeyEvent) -> Result<()> {
+        let count = self.issues.len();
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Up => {
+                self.table_selected = self.table_selected.saturating_sub(1);
+            }
+            Action::Down => {
+                self.table_selected = (self.table_selected + 1).min(count.saturating_sub(1));
+            }
+            Action::Top => {
+                self.table_selected = 0;
+            }
+            Action::Bottom => {
+                self.table_selected = count.saturating_sub(1);
+            }
+            Action::Left => {
+                self.table_cursor = self.table_cursor.saturating_sub(1);
+            }
+            Action::Right => {
+                self.table_cursor =
+                    (self.table_cursor + 1).min(self.table_columns.len().saturating_sub(1));
+            }
+            Action::Sort => {
+                if let Some(&column) = self.table_columns.get(self.table_cursor) {
+                    if column == self.table_sort {
+                        self.table_sort_desc = !self.table_sort_desc;
+                    } else {
+                        self.table_sort = column;
+                        self.table_sort_desc = false;
+                    }
+                }
+            }
+            Action::ToggleColumn => {
+                if let KeyCode::Char(c @ '1'..='9') = key.code {
+                    let idx = c as usize - '1' as usize;
+                    if let Some(&column) = TableColumn::ALL.get(idx) {
+                        self.toggle_table_column(column);
+                    }
+                }
+            }
+            Action::Open => {
+                if let Some(issue_id) = self
+                    .table_issues()
+                    .get(self.table_selected)
+                    .map(|i| i.id.clone())
+                {
+                    self.open_detail_from(&issue_id, View::Table);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_dashboard_key(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Refresh => {
+                self.try_reload_issues();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_burndown_key(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Refresh => {
+                self.try_reload_issues();
+            }
+            Action::ToggleBurnMode => {
+                self.burndown_mode = match self.burndown_mode {
+                    BurnMode::Down => BurnMode::Up,
+                    BurnMode::Up => BurnMode::Down,
+                };
+            }
+            Action::NextEpic => {
+                // Cycle label scope -> each epic -> label scope
+                let epics: Vec<String> = self
+                    .epic_rows()
+                    .into_iter()
+                    .filter(|r| r.depth == 0)
+                    .map(|r| r.issue_id)
+                    .collect();
+                let next = match self.burndown_epic {
+                    None => 0,
+                    Some(ref current) => {
+                        epics.iter().position(|e| e == current).map_or(0, |i| i + 1)
+                    }
+                };
+                self.burndown_epic = epics.get(next).cloned();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_flow_key(&mut self, action: Action) -> Result<()> {
+        let today = Utc::now().date_naive();
+        // Pan by half the shown range
+        let step = FLOW_SPANS[self.flow_span].map_or(0, |days| (days / 2).max(1));
+        match action {
+            Action::Back => {
+                self.current_view = View::Board;
+            }
+            Action::Refresh => {
+                self.try_reload_issues();
+            }
+            Action::Left => {
+                self.flow_offset = (self.flow_offset + step).min(self.max_flow_offset(today));
+            }
+            Action::Right => {
+                self.flow_offset = self.flow_offset.saturating_sub(step);
+            }
+            Action::Bottom => {
+                self.flow_offset = 0;
+            }
+            Action::ShorterRange => {
+                self.flow_span = self.flow_span.saturating_sub(1);
+            }
+            Action::LongerRange => {
+                self.flow_span = (self.flow_span + 1).min(FLOW_SPANS.len() - 1);
+                self.flow_offset = self.flow_offset.min(self.max_flow_offset(today));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Shows or hides a table column, keeping at least one visible.
+    fn toggle_table_column(&mut self, column: TableColumn) {
+        if let Some(pos) = self.table_columns.iter().position(|&c| c == column) {
+            if self.table_columns.len() > 1 {
+                self.table_columns.remove(pos);
+            }
+        } else {
+            self.table_columns.push(column);
+            self.table_columns
+                .sort_by_key(|c| TableColumn::ALL.iter().position(|a| a == c));
+        }
+        self.table_cursor = self.table_cursor.min(self.table_columns.len() - 1);
+    }
+
+    /// Navigates to the selected search result's position on the board and opens it.
+    fn open_search_result(&mut self) {
+        if let Some(result) = self.search_results.get(self.search_selected) {
+            let issue_id = result.issue_id.clone();
+            if self.reveal_on_board(&issue_id) {
+                self.open_detail();
+            } else {
+                self.status_message = Some(format!("{} isn't shown by any board view", issue_id));
+            }
+        }
+    }
+
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
+        match self.current_view {
+            View::Board => self.handle_board_mouse(mouse),
+            View::Detail => self.handle_detail_mouse(mouse),
+            View::Search => self.handle_search_mouse(mouse),
+            View::Epics
+            | View::Graph
+            | View::Ready
+            | View::Blocked
+            | View::Table
+            | View::Dashboard
+            | View::Burndown
+            | View::Flow => {}
+        }
+        Ok(())
+    }
+
+    /// Records a click on an issue and reports whether it completes a double-click.
+    fn register_click(&mut self, issue_id: &str) -> bool {
+        let now = Instant::now();
+        let is_double = self
+            .last_click
+            .as_ref()
+            .is_some_and(|(at, id)| id == issue_id && now.duration_since(*at) <= DOUBLE_CLICK);
+        self.last_click = if is_double {
+            None
+        } else {
+            Some((now, issue_id.to_string()))
+        };
+        is_double
+    }
+
+    fn handle_board_mouse(&mut self, mouse: MouseEvent) {
+        let (x, y) = (mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some((column, idx)) = self.board_layout.card_at(x, y) {
+                    self.selected_column = column;
+                    self.selected_index = idx;
+                    let Some(issue_id) = self.selected_issue().map(|i| i.id.clone()) else {
+                        return;
+                    };
+                    if self.register_click(&issue_id) {
+                        self.drag = None;
+                        self.open_detail();
+                    } else {
+                        self.drag = Some(DragState {
+                            issue_id,
+                            from: column,
+                            over: Some(column),
+                        });
+                    }
+                } else if let Some(column) = self.board_layout.column_at(x, y) {
+                    if column != self.selected_column {
+                        self.selected_column = column;
+                        self.selected_index = 0;
+                    }
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let over = self.board_layout.column_at(x, y);
+                if let Some(ref mut drag) = self.drag {
+                    drag.over = over;
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if let Some(drag) = self.drag.take() {
+                    match drag.over {
+                        Some(column) if column != drag.from => {
+                            self.move_issue(&drag.issue_id, column);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+                if let Some(column) = self.board_layout.column_at(x, y) {
+                    if column != self.selected_column {
+                        self.selected_column = column;
+                        self.selected_index = 0;
+                    }
+                    if mouse.kind == MouseEventKind::ScrollDown {
+                        self.select_next();
+                    } else {
+                        self.select_prev();
+                    }
+                }
+            }
+            _ => {}
         }
+    }
+
+    fn handle_detail_mouse(&mut self, mouse: MouseEvent) {
+        match mouse.kind {
+            MouseEventKind::ScrollDown => {
+                self.detail_scroll =
+                    (self.detail_scroll + WHEEL_SCROLL_LINES).min(self.detail_scroll_max);
+            }
+            MouseEventKind::ScrollUp => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(WHEEL_SCROLL_LINES);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_search_mouse(&mut self, mouse: MouseEvent) {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(idx) = self.search_layout.result_at(mouse.column, mouse.row) else {
+                    return;
+                };
+                let Some(issue_id) = self.search_results.get(idx).map(|r| r.issue_id.clone())
+                else {
+                    return;
+                };
+                self.search_selected = idx;
+                if self.register_click(&issue_id) {
+                    self.open_search_result();
+                }
+            }
+            MouseEventKind::ScrollDown => {
+                self.search_selected =
+                    (self.search_selected + 1).min(self.search_results.len().saturating_sub(1));
+            }
+            MouseEventKind::ScrollUp => {
+                self.search_selected = self.search_selected.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn update_search_results(&mut self) {
+        // On a syntax error keep the last results up while the query is fixed
+        let query = match Query::parse(&self.search_query, self.search_mode) {
+            Ok(query) => query,
+            Err(e) => {
+                self.search_error = Some(e);
+                return;
+            }
+        };
+        // Likewise for an invalid regex, marking the whole query
+        let matcher = query
+            .bare_text()
+            .map(|text| TextMatcher::new(self.search_mode, &text))
+            .transpose();
+        let matcher = match matcher {
+            Ok(matcher) => matcher,
+            Err(message) => {
+                self.search_error = Some(QueryError {
+                    message,
+                    start: 0,
+                    end: self.search_query.chars().count(),
+                });
+                return;
+            }
+        };
+        self.search_error = None;
+
+        self.search_results = self
+            .search_index
+            .search(&self.issues, &query, matcher.as_ref())
+            .into_iter()
+            .map(|pos| SearchResult {
+                issue_id: self.issues[pos].id.clone(),
+                matches: OnceCell::new(),
+            })
+            .collect();
+        self.search_matcher = matcher;
+        self.search_phrases = query.phrase_matchers();
         // Clamp selection
         if self.search_results.is_empty() {
             self.search_selected = 0;
@@ -336,6 +2104,15 @@ impl App {
         }
     }
 
+    /// Where the search query matched in a result, for highlighting.
+    pub fn search_matches<'a>(&self, result: &'a SearchResult) -> &'a [FieldMatch] {
+        result.matches.get_or_init(|| {
+            self.issue(&result.issue_id).map_or(Vec::new(), |issue| {
+                field_matches(issue, self.search_matcher.as_ref(), &self.search_phrases)
+            })
+        })
+    }
+
     fn clamp_selection(&mut self) {
         let count = self.get_column_issues(self.selected_column).len();
         if count == 0 {
@@ -353,3 +2130,36 @@ impl App {
         }
     }
 }
+
+/// Per-field matches of the bare words and quoted phrases, for highlighting.
+fn field_matches(
+    issue: &Issue,
+    matcher: Option<&TextMatcher>,
+    phrases: &[TextMatcher],
+) -> Vec<FieldMatch> {
+    let fields = [
+        (SearchField::Title, Some(issue.title.clone())),
+        (SearchField::Description, issue.description.clone()),
+        (SearchField::Labels, Some(issue.labels.join(", "))),
+        (SearchField::Assignee, issue.assignee.clone()),
+    ];
+    fields
+        .into_iter()
+        .filter_map(|(field, text)| {
+            let indices = match_indices(&text?, matcher, phrases);
+            (!indices.is_empty()).then_some(FieldMatch { field, indices })
+        })
+        .collect()
+}
+
+/// Char indices of `text` matched by the bare words or the first occurrence
+/// of each phrase, sorted.
+fn match_indices(text: &str, matcher: Option<&TextMatcher>, phrases: &[TextMatcher]) -> Vec<usize> {
+    let mut indices = matcher.map(|m| m.indices(text)).unwrap_or_default();
+    for phrase in phrases {
+        indices.extend(phrase.indices(text));
+    }
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
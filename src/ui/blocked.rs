@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::keymap;
+use super::theme::*;
+use crate::beads::{Issue, IssueGraph};
+
+/// Root causes listed in the summary line.
+const TOP_ROOT_CAUSES: usize = 3;
+
+pub fn render_blocked(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Root cause summary
+            Constraint::Min(1),    // Blocked list
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    let graph = IssueGraph::new(&app.issues);
+    let blocked = app.blocked_issues();
+    let chains: Vec<Vec<Vec<&str>>> = blocked.iter().map(|i| graph.blocker_chains(i)).collect();
+
+    render_root_causes(f, chunks[0], &graph, &chains);
+    render_blocked_list(f, chunks[1], app, &graph, &blocked, &chains);
+    render_blocked_footer(f, chunks[2]);
+}
+
+/// Summarizes which root causes unblock the most issues.
+fn render_root_causes(f: &mut Frame, area: Rect, graph: &IssueGraph, chains: &[Vec<Vec<&str>>]) {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for issue_chains in chains {
+        let mut roots: Vec<&str> = issue_chains.iter().filter_map(|c| c.last().copied()).collect();
+        roots.sort_unstable();
+        roots.dedup();
+        for root in roots {
+            *counts.entry(root).or_default() += 1;
+        }
+    }
+    let mut ranked: Vec<(&str, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut spans = vec![Span::styled(
+        "Unblock first: ",
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    if ranked.is_empty() {
+        spans.push(Span::styled("nothing is blocked", Style::default().fg(COLOR_DONE)));
+    }
+    for (i, (root, count)) in ranked.iter().take(TOP_ROOT_CAUSES).enumerate() {
+        if i > 0 {
+            spans.push(Span::styled("  │  ", Style::default().fg(COLOR_SEPARATOR)));
+        }
+        let color = graph
+            .issue(root)
+            .map(|issue| status_color(issue.status))
+            .unwrap_or(COLOR_SECONDARY_TEXT);
+        spans.push(Span::styled(
+            root.to_string(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(
+            format!(" ({} blocked)", count),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(Style::default().fg(COLOR_BLOCKED));
+    f.render_widget(Paragraph::new(Line::from(spans)).block(block), area);
+}
+
+fn render_blocked_list(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    graph: &IssueGraph,
+    blocked: &[&Issue],
+    chains: &[Vec<Vec<&str>>],
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Blocked ({}) ", blocked.len()))
+        .style(Style::default());
+
+    let items: Vec<ListItem> = blocked
+        .iter()
+        .zip(chains)
+        .enumerate()
+        .map(|(idx, (issue, issue_chains))| {
+            format_blocked_item(graph, issue, issue_chains, idx == app.blocked_selected)
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    let mut state = ListState::default().with_selected(Some(app.blocked_selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn format_blocked_item<'a>(
+    graph: &IssueGraph,
+    issue: &'a Issue,
+    chains: &[Vec<&str>],
+    is_selected: bool,
+) -> ListItem<'a> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!("{} ", issue.priority.label()),
+            Style::default()
+                .fg(priority_color(issue.priority.0))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} ", issue.id),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ),
+        Span::raw(issue.title.as_str()),
+        Span::styled(" 🚫", Style::default().fg(COLOR_BLOCKED)),
+    ])];
+
+    for (i, chain) in chains.iter().enumerate() {
+        let branch = if i + 1 == chains.len() { "└─ " } else { "├─ " };
+        let mut spans = vec![Span::styled(
+            format!("   {}", branch),
+            Style::default().fg(COLOR_SEPARATOR),
+        )];
+        if chain.is_empty() {
+            spans.push(Span::styled(
+                "marked blocked, no open blockers",
+                Style::default().fg(COLOR_SECONDARY_TEXT),
+            ));
+        }
+        for (j, id) in chain.iter().enumerate() {
+            if j > 0 {
+                spans.push(Span::styled(" ← ", Style::default().fg(COLOR_SEPARATOR)));
+            }
+            let (color, status) = graph
+                .issue(id)
+                .map(|b| (status_color(b.status), b.status.to_string()))
+                .unwrap_or((COLOR_SECONDARY_TEXT, "not loaded".to_string()));
+            let is_root = j + 1 == chain.len();
+            let mut style = Style::default().fg(color);
+            if is_root {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            spans.push(Span::styled(id.to_string(), style));
+            spans.push(Span::styled(
+                format!(" ({})", status),
+                Style::default().fg(COLOR_SECONDARY_TEXT),
+            ));
+            if is_root {
+                spans.push(Span::styled(" ★ root", Style::default().fg(COLOR_BLOCKS)));
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let style = if is_selected {
+        Style::default()
+            .bg(COLOR_SELECTED_BG)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    ListItem::new(lines).style(style)
+}
+
+fn render_blocked_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Blocked);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
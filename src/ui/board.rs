@@ -1,16 +1,82 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Tabs, Wrap},
     Frame,
 };
 
-use super::app::{App, Column};
+use super::activity::render_activity;
+use super::app::{App, CardDensity, Column};
+use super::detail::render_detail_preview;
+use super::format::{highlight_matches, relative_age, wrap_text};
+use super::keymap;
 use super::theme::*;
 use crate::beads::Issue;
+use crate::beads::query::SearchMode;
+
+/// Screen regions of the last rendered board, used for mouse hit-testing.
+#[derive(Debug, Clone, Default)]
+pub struct BoardLayout {
+    pub columns: Vec<ColumnLayout>,
+    pub tabs: Vec<(Column, Rect)>,
+    /// The activity feed, when there was room to draw it
+    pub activity: Option<Rect>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnLayout {
+    pub column: Column,
+    pub area: Rect,
+    /// (index within the column, screen area) for each visible card.
+    pub cards: Vec<(usize, Rect)>,
+}
+
+impl BoardLayout {
+    pub fn column_at(&self, x: u16, y: u16) -> Option<Column> {
+        let pos = Position::new(x, y);
+        self.columns
+            .iter()
+            .find(|c| c.area.contains(pos))
+            .map(|c| c.column)
+            .or_else(|| {
+                self.tabs
+                    .iter()
+                    .find(|(_, rect)| rect.contains(pos))
+                    .map(|(column, _)| *column)
+            })
+    }
+
+    pub fn card_at(&self, x: u16, y: u16) -> Option<(Column, usize)> {
+        let pos = Position::new(x, y);
+        self.columns.iter().find_map(|c| {
+            c.cards
+                .iter()
+                .find(|(_, rect)| rect.contains(pos))
+                .map(|(idx, _)| (c.column, *idx))
+        })
+    }
+}
+
+/// Below this width the three columns no longer fit side by side.
+const NARROW_WIDTH: u16 = 90;
+/// Smallest terminal that can still show a single column.
+const MIN_WIDTH: u16 = 30;
+const MIN_HEIGHT: u16 = 8;
+/// Below this width the preview pane is hidden even when enabled.
+const MIN_PREVIEW_WIDTH: u16 = 60;
+/// Below this width the activity feed is hidden even when enabled.
+const MIN_ACTIVITY_WIDTH: u16 = 80;
+const ACTIVITY_WIDTH: u16 = 36;
+
+/// Renders the board view. Returns the layout of columns and cards for mouse handling.
+pub fn render_board(f: &mut Frame, app: &App) -> BoardLayout {
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        render_too_small(f, area);
+        return BoardLayout::default();
+    }
 
-pub fn render_board(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -21,8 +87,32 @@ pub fn render_board(f: &mut Frame, app: &App) {
         .split(f.area());
 
     render_header(f, chunks[0], app);
-    render_columns(f, chunks[1], app);
+    let mut board_area = chunks[1];
+    let mut activity = None;
+    if app.show_activity && area.width >= MIN_ACTIVITY_WIDTH {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(ACTIVITY_WIDTH)])
+            .split(board_area);
+        render_activity(f, panes[1], app);
+        activity = Some(panes[1]);
+        board_area = panes[0];
+    }
+    let layout = if app.preview && area.width >= MIN_PREVIEW_WIDTH {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(100 - app.preview_width),
+                Constraint::Percentage(app.preview_width),
+            ])
+            .split(board_area);
+        render_detail_preview(f, panes[1], app);
+        render_columns(f, panes[0], app)
+    } else {
+        render_columns(f, board_area, app)
+    };
     render_footer(f, chunks[2], app);
+    BoardLayout { activity, ..layout }
 }
 
 fn render_header(f: &mut Frame, area: Rect, app: &App) {
@@ -32,7 +122,7 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
         .unwrap_or_else(|| "📋 all issues".to_string());
 
     // Create multi-span line with visual separators
-    let header_line = Line::from(vec![
+    let mut spans = vec![
         Span::styled(" ▓▓ ", Style::default()
             .fg(COLOR_HEADER)
             .bg(COLOR_HEADER_BG)
@@ -43,8 +133,42 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
         Span::styled("  │  ", Style::default().fg(COLOR_SEPARATOR)),
         Span::raw("Beads Kanban"),
         Span::styled("  │  ", Style::default().fg(COLOR_SEPARATOR)),
-        Span::styled(&label, Style::default().fg(COLOR_IN_PROGRESS)),
-    ]);
+        Span::styled(label, Style::default().fg(COLOR_IN_PROGRESS)),
+    ];
+
+    // Saved views, numbered for their shortcut keys
+    if app.tabs.len() > 1 {
+        spans.push(Span::styled("  │ ", Style::default().fg(COLOR_SEPARATOR)));
+        for (i, tab) in app.tabs.iter().enumerate() {
+            let style = if i == app.current_tab {
+                Style::default()
+                    .fg(COLOR_HEADER)
+                    .bg(COLOR_SELECTED_BG)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(COLOR_HELP_TEXT)
+            };
+            spans.push(Span::styled(format!(" {} {} ", i + 1, tab.name), style));
+        }
+    }
+
+    // Per-assignee WIP limit violations
+    if let Some(limit) = app.config.wip_limits.per_assignee {
+        let over = app.assignees_over_limit();
+        if !over.is_empty() {
+            spans.push(Span::styled("  │  ", Style::default().fg(COLOR_SEPARATOR)));
+            let text = over
+                .iter()
+                .map(|(assignee, count)| format!("{} {}/{}", assignee, count, limit))
+                .collect::<Vec<_>>()
+                .join(", ");
+            spans.push(Span::styled(
+                format!("⚠ WIP {}", text),
+                Style::default().fg(COLOR_WARNING).add_modifier(Modifier::BOLD),
+            ));
+        }
+    }
+    let header_line = Line::from(spans);
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -58,33 +182,147 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, area);
 }
 
-fn render_columns(f: &mut Frame, area: Rect, app: &App) {
-    let columns = Layout::default()
+fn render_too_small(f: &mut Frame, area: Rect) {
+    let lines = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().fg(COLOR_BLOCKED).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!("{}x{} (need {}x{})", area.width, area.height, MIN_WIDTH, MIN_HEIGHT),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        )),
+    ];
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+fn render_columns(f: &mut Frame, area: Rect, app: &App) -> BoardLayout {
+    if area.width < NARROW_WIDTH {
+        return render_column_tabs(f, area, app);
+    }
+
+    let visible = &app.tab().columns;
+    let areas = Layout::default()
         .direction(Direction::Horizontal)
+        .constraints(visible.iter().map(|_| Constraint::Ratio(1, visible.len() as u32)))
+        .split(area);
+
+    BoardLayout {
+        columns: visible
+            .iter()
+            .zip(areas.iter())
+            .map(|(&column, &column_area)| render_column(f, column_area, app, column))
+            .collect(),
+        tabs: Vec::new(),
+        activity: None,
+    }
+}
+
+/// Narrow layout: one column at a time, with tab headers for switching.
+fn render_column_tabs(f: &mut Frame, area: Rect, app: &App) -> BoardLayout {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
-            Constraint::Percentage(34),
+            Constraint::Length(1), // Tabs
+            Constraint::Min(0),    // Selected column
         ])
         .split(area);
 
-    render_column(f, columns[0], app, Column::Open);
-    render_column(f, columns[1], app, Column::InProgress);
-    render_column(f, columns[2], app, Column::Done);
+    let columns = &app.tab().columns;
+    let titles: Vec<(String, Color)> = columns
+        .iter()
+        .map(|&column| column_title(app, column))
+        .collect();
+
+    // Tabs pads each title with one space on either side and a one-cell divider
+    let mut tab_rects = Vec::new();
+    let mut x = chunks[0].x;
+    for (&column, (title, _)) in columns.iter().zip(&titles) {
+        let width = title.chars().count() as u16 + 2;
+        tab_rects.push((column, Rect::new(x, chunks[0].y, width, 1).intersection(chunks[0])));
+        x = x.saturating_add(width + 1);
+    }
+
+    let titles: Vec<Line> = titles
+        .into_iter()
+        .map(|(title, color)| Line::from(Span::styled(title, Style::default().fg(color))))
+        .collect();
+    let selected = columns
+        .iter()
+        .position(|&c| c == app.selected_column)
+        .unwrap_or(0);
+
+    let tabs = Tabs::new(titles)
+        .select(selected)
+        .divider(Span::styled("│", Style::default().fg(COLOR_SEPARATOR)))
+        .highlight_style(
+            Style::default()
+                .bg(COLOR_SELECTED_BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_widget(tabs, chunks[0]);
+
+    BoardLayout {
+        columns: vec![render_column(f, chunks[1], app, app.selected_column)],
+        tabs: tab_rects,
+        activity: None,
+    }
+}
+
+/// Column title with its count (and WIP limit, if configured) plus the column's
+/// color, or the warning color when the limit is exceeded.
+/// While the board is filtered the count reads `shown/total`; limits always
+/// apply to the total.
+fn column_title(app: &App, column: Column) -> (String, Color) {
+    let count = app.column_issues_unfiltered(column).len();
+    let (name, color) = match column {
+        Column::Open => ("OPEN", COLOR_OPEN),
+        Column::InProgress => ("IN PROGRESS", COLOR_IN_PROGRESS),
+        Column::Done => ("DONE", COLOR_DONE),
+    };
+    let limit = app.column_limit(column);
+    let counts = match (app.board_filter.is_some(), limit) {
+        (false, Some(limit)) => format!("{}/{}", count, limit),
+        (false, None) => count.to_string(),
+        (true, Some(limit)) => {
+            format!("{}/{}, limit {}", app.get_column_issues(column).len(), count, limit)
+        }
+        (true, None) => format!("{}/{}", app.get_column_issues(column).len(), count),
+    };
+    let color = if limit.is_some_and(|limit| count > limit) {
+        COLOR_WARNING
+    } else {
+        color
+    };
+    (format!("{} ({})", name, counts), color)
+}
+
+fn is_over_limit(app: &App, column: Column) -> bool {
+    app.column_limit(column)
+        .is_some_and(|limit| app.column_issues_unfiltered(column).len() > limit)
 }
 
-fn render_column(f: &mut Frame, area: Rect, app: &App, column: Column) {
+fn render_column(f: &mut Frame, area: Rect, app: &App, column: Column) -> ColumnLayout {
     let issues = app.get_column_issues(column);
     let is_selected = app.selected_column == column;
+    let is_drop_target = app
+        .drag
+        .as_ref()
+        .is_some_and(|d| d.over == Some(column) && d.from != column);
 
-    let (title, color) = match column {
-        Column::Open => (format!("OPEN ({})", issues.len()), COLOR_OPEN),
-        Column::InProgress => (format!("IN PROGRESS ({})", issues.len()), COLOR_IN_PROGRESS),
-        Column::Done => (format!("DONE ({})", issues.len()), COLOR_DONE),
-    };
+    let (title, color) = column_title(app, column);
 
-    let border_style = if is_selected {
+    let border_style = if is_drop_target {
+        Style::default()
+            .fg(COLOR_BORDER_ACTIVE)
+            .add_modifier(Modifier::BOLD)
+    } else if is_selected {
         Style::default().fg(color).add_modifier(Modifier::BOLD)
+    } else if is_over_limit(app, column) {
+        Style::default().fg(COLOR_WARNING)
     } else {
         Style::default().fg(COLOR_BORDER)
     };
@@ -94,21 +332,68 @@ fn render_column(f: &mut Frame, area: Rect, app: &App, column: Column) {
         .borders(Borders::ALL)
         .style(border_style);
 
+    let inner = block.inner(area);
+    let card_width = inner.width as usize;
     let items: Vec<ListItem> = issues
         .iter()
         .enumerate()
         .map(|(idx, issue)| {
             let is_item_selected = is_selected && idx == app.selected_index;
-            format_issue_item(issue, is_item_selected)
+            match app.card_density {
+                CardDensity::Compact => {
+                    let matches = app
+                        .board_filter
+                        .as_ref()
+                        .and_then(|filter| filter.title_matches(issue))
+                        .unwrap_or_default();
+                    format_issue_item(issue, is_item_selected, &matches)
+                }
+                CardDensity::Expanded => format_issue_card(issue, is_item_selected, card_width),
+            }
         })
         .collect();
+    let heights: Vec<u16> = items.iter().map(|item| item.height() as u16).collect();
 
     let list = List::new(items).block(block);
 
-    f.render_widget(list, area);
+    // Keep the selected card scrolled into view
+    let mut state = ListState::default();
+    if is_selected {
+        state.select(Some(app.selected_index));
+    }
+    f.render_stateful_widget(list, area, &mut state);
+
+    // Record where each visible card landed
+    let mut cards = Vec::new();
+    let mut y = inner.y;
+    for (idx, &height) in heights.iter().enumerate().skip(state.offset()) {
+        if y >= inner.bottom() {
+            break;
+        }
+        let rect = Rect::new(inner.x, y, inner.width, height).intersection(inner);
+        cards.push((idx, rect));
+        y = y.saturating_add(height);
+    }
+
+    ColumnLayout {
+        column,
+        area,
+        cards,
+    }
+}
+
+fn item_style(is_selected: bool) -> Style {
+    if is_selected {
+        Style::default()
+            .bg(COLOR_SELECTED_BG)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    }
 }
 
-fn format_issue_item(issue: &Issue, is_selected: bool) -> ListItem<'_> {
+/// One-line card; `matches` are title characters to highlight for the live filter.
+fn format_issue_item<'a>(issue: &'a Issue, is_selected: bool, matches: &[usize]) -> ListItem<'a> {
     let priority_clr = priority_color(issue.priority.0);
 
     let mut spans = vec![
@@ -118,8 +403,15 @@ fn format_issue_item(issue: &Issue, is_selected: bool) -> ListItem<'_> {
         ),
         Span::styled(&issue.id, Style::default().fg(COLOR_SECONDARY_TEXT)),
         Span::raw(" "),
-        Span::raw(&issue.title),
     ];
+    if matches.is_empty() {
+        spans.push(Span::raw(&issue.title));
+    } else {
+        let matched = Style::default()
+            .fg(COLOR_SEARCH_MATCH)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        spans.extend(highlight_matches(&issue.title, matches, Style::default(), matched));
+    }
 
     if issue.is_blocked() {
         spans.push(Span::raw(" "));
@@ -129,22 +421,109 @@ fn format_issue_item(issue: &Issue, is_selected: bool) -> ListItem<'_> {
         ));
     }
 
-    let style = if is_selected {
-        Style::default()
-            .bg(COLOR_SELECTED_BG)
-            .add_modifier(Modifier::BOLD)
-    } else {
-        Style::default()
-    };
+    ListItem::new(Line::from(spans)).style(item_style(is_selected))
+}
+
+/// Multi-line card: header, wrapped title, then type/labels/assignee/age and
+/// dependency counts.
+fn format_issue_card(issue: &Issue, is_selected: bool, width: usize) -> ListItem<'_> {
+    let priority_clr = priority_color(issue.priority.0);
+    let mut lines = Vec::new();
+
+    let mut header = vec![
+        Span::styled(
+            format!("{} ", issue.priority.label()),
+            Style::default().fg(priority_clr).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(&issue.id, Style::default().fg(COLOR_SECONDARY_TEXT)),
+    ];
+    if issue.is_blocked() {
+        header.push(Span::raw(" "));
+        header.push(Span::styled("🚫", Style::default().fg(COLOR_BLOCKED)));
+    }
+    lines.push(Line::from(header));
+
+    for title_line in wrap_text(&issue.title, width) {
+        lines.push(Line::from(Span::styled(
+            title_line,
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    let mut meta = vec![Span::raw(format!("{} {}", issue.issue_type.icon(), issue.issue_type))];
+    if !issue.labels.is_empty() {
+        meta.push(Span::styled(" · ", Style::default().fg(COLOR_SEPARATOR)));
+        meta.push(Span::styled(
+            issue.labels.join(", "),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ));
+    }
+    if let Some(ref assignee) = issue.assignee {
+        meta.push(Span::styled(" · ", Style::default().fg(COLOR_SEPARATOR)));
+        meta.push(Span::styled(
+            format!("@{}", assignee),
+            Style::default().fg(COLOR_IN_PROGRESS),
+        ));
+    }
+    if let Some(age) = relative_age(&issue.created_at) {
+        meta.push(Span::styled(" · ", Style::default().fg(COLOR_SEPARATOR)));
+        meta.push(Span::styled(age, Style::default().fg(COLOR_SECONDARY_TEXT)));
+    }
+    lines.push(Line::from(meta));
 
-    ListItem::new(Line::from(spans)).style(style)
+    if issue.dependency_count > 0 || issue.dependent_count > 0 {
+        let mut deps = Vec::new();
+        if issue.dependency_count > 0 {
+            deps.push(Span::styled(
+                format!("⛔ {} blocker(s)", issue.dependency_count),
+                Style::default().fg(COLOR_BLOCKED),
+            ));
+        }
+        if issue.dependent_count > 0 {
+            if !deps.is_empty() {
+                deps.push(Span::raw("  "));
+            }
+            deps.push(Span::styled(
+                format!("⏩ blocks {}", issue.dependent_count),
+                Style::default().fg(COLOR_BLOCKS),
+            ));
+        }
+        lines.push(Line::from(deps));
+    }
+
+    // Blank separator between cards
+    lines.push(Line::from(""));
+
+    ListItem::new(lines).style(item_style(is_selected))
+}
+
+/// The filter's prompt, naming its mode unless it's the usual fuzzy.
+fn filter_prompt(mode: SearchMode) -> String {
+    match mode {
+        SearchMode::Fuzzy => "Filter: ".to_string(),
+        mode => format!("Filter ({}): ", mode.label()),
+    }
 }
 
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
-    let paragraph = if let Some(ref msg) = app.status_message {
+    let paragraph = if let Some(ref filter) = app.board_filter {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let mut spans = vec![
+            Span::styled(filter_prompt(filter.mode), bold.fg(COLOR_SEARCH_BORDER)),
+            Span::styled(format!("{}_", filter.input), bold),
+            Span::raw("  "),
+        ];
+        // A syntax error replaces the key help until it's fixed
+        let (hint, color) = match filter.error {
+            Some(ref error) => (error.to_string(), COLOR_BLOCKED),
+            None => (keymap::footer_for(keymap::current_bindings(app)), COLOR_HELP_TEXT),
+        };
+        spans.push(Span::styled(hint, Style::default().fg(color)));
+        Paragraph::new(Line::from(spans))
+    } else if let Some(ref msg) = app.status_message {
         Paragraph::new(msg.as_str()).style(Style::default().fg(COLOR_BLOCKED))
     } else {
-        let help = "[←/→ or h/l] Navigate  [↑/↓ or k/j] Select  [Enter] Details  [/] Search  [r] Refresh  [q] Quit";
+        let help = keymap::footer_for(keymap::current_bindings(app));
         Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT))
     };
     f.render_widget(paragraph, area);
@@ -0,0 +1,196 @@
+use chrono::{Duration, NaiveDate, Utc};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, BorderType, Borders, Chart, Dataset, GraphType, Paragraph},
+    Frame,
+};
+
+use super::app::{App, BurnMode, View};
+use super::keymap;
+use super::theme::*;
+use crate::beads::stats::BurnSeries;
+
+/// Days of recent history used for the completion projection's closing rate.
+const RATE_WINDOW_DAYS: usize = 14;
+/// Projections further out than this are cut off.
+const MAX_PROJECTION_DAYS: usize = 365;
+
+pub fn render_burndown(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Chart
+            Constraint::Length(1), // Summary
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    let (scope_name, issues) = app.burndown_scope();
+    let today = Utc::now().date_naive();
+    match BurnSeries::build(&issues, today) {
+        Some(series) => {
+            let completion = series.projected_completion(RATE_WINDOW_DAYS);
+            render_chart(f, chunks[0], app, &scope_name, &series, completion);
+            render_summary(f, chunks[1], &series, completion);
+        }
+        None => {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(format!(" {} ", scope_name));
+            let paragraph = Paragraph::new("No issues with timestamps in this scope.")
+                .block(block)
+                .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+            f.render_widget(paragraph, chunks[0]);
+        }
+    }
+    render_burndown_footer(f, chunks[2]);
+}
+
+fn points(values: &[u64]) -> Vec<(f64, f64)> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(day, &v)| (day as f64, v as f64))
+        .collect()
+}
+
+fn render_chart(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    scope_name: &str,
+    series: &BurnSeries,
+    completion: Option<usize>,
+) {
+    let today = series.scope.len() - 1;
+    let scope_now = series.scope[today] as f64;
+    let completion = completion.filter(|&day| day <= today + MAX_PROJECTION_DAYS);
+    let last_day = completion.unwrap_or(today).max(today).max(1);
+
+    let scope = points(&series.scope);
+    let closed = points(&series.closed);
+    let open = points(&series.open());
+
+    let (title, mut datasets, projection) = match app.burndown_mode {
+        BurnMode::Down => {
+            let projection =
+                completion.map(|day| vec![(today as f64, open[today].1), (day as f64, 0.0)]);
+            let datasets = vec![Dataset::default()
+                .name("Open")
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(COLOR_OPEN))
+                .data(&open)];
+            ("Burndown", datasets, projection)
+        }
+        BurnMode::Up => {
+            let projection = completion
+                .map(|day| vec![(today as f64, closed[today].1), (day as f64, scope_now)]);
+            let datasets = vec![
+                Dataset::default()
+                    .name("Scope")
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(COLOR_SECONDARY_TEXT))
+                    .data(&scope),
+                Dataset::default()
+                    .name("Closed")
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(COLOR_DONE))
+                    .data(&closed),
+            ];
+            ("Burn-up", datasets, projection)
+        }
+    };
+    if let Some(ref projection) = projection {
+        datasets.push(
+            Dataset::default()
+                .name("Projected")
+                .marker(Marker::Dot)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(COLOR_IN_PROGRESS))
+                .data(projection),
+        );
+    }
+
+    let date_label = |day: usize| {
+        (series.start + Duration::days(day as i64))
+            .format("%m-%d")
+            .to_string()
+    };
+    let x_labels = vec![
+        Span::raw(date_label(0)),
+        Span::raw(date_label(last_day / 2)),
+        Span::raw(date_label(last_day)),
+    ];
+    let y_max = scope_now.max(1.0);
+    let y_labels = vec![
+        Span::raw("0"),
+        Span::raw(format!("{}", (y_max / 2.0).round())),
+        Span::raw(format!("{}", y_max)),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} · {} ", title, scope_name))
+        .style(Style::default().fg(COLOR_HEADER));
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_BORDER))
+                .bounds([0.0, last_day as f64])
+                .labels(x_labels),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_BORDER))
+                .bounds([0.0, y_max])
+                .labels(y_labels),
+        );
+    f.render_widget(chart, area);
+}
+
+fn render_summary(f: &mut Frame, area: Rect, series: &BurnSeries, completion: Option<usize>) {
+    let today = series.scope.len() - 1;
+    let open = series.open()[today];
+    let rate = series.recent_rate(RATE_WINDOW_DAYS);
+    let forecast = match completion {
+        Some(_) if open == 0 => "all done".to_string(),
+        Some(day) => {
+            let date: NaiveDate = series.start + Duration::days(day as i64);
+            format!("projected completion {}", date.format("%Y-%m-%d"))
+        }
+        None => "no completion forecast (nothing closed recently)".to_string(),
+    };
+
+    let label = Style::default().fg(COLOR_SECONDARY_TEXT);
+    let value = Style::default().add_modifier(Modifier::BOLD);
+    let line = Line::from(vec![
+        Span::styled("Scope ", label),
+        Span::styled(series.scope[today].to_string(), value),
+        Span::styled("  Closed ", label),
+        Span::styled(series.closed[today].to_string(), value.fg(COLOR_DONE)),
+        Span::styled("  Open ", label),
+        Span::styled(open.to_string(), value.fg(COLOR_OPEN)),
+        Span::styled(
+            format!("  Rate {:.1}/day (last {} days)  ", rate, RATE_WINDOW_DAYS),
+            label,
+        ),
+        Span::styled(forecast, Style::default().fg(COLOR_IN_PROGRESS)),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+fn render_burndown_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Burndown);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
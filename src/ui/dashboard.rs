@@ -0,0 +1,220 @@
+use chrono::Utc;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders, Paragraph, Sparkline},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::format::{format_duration, relative_age};
+use super::keymap;
+use super::theme::*;
+use crate::beads::models::IssueType;
+use crate::beads::{stats, Status};
+
+/// Days of history in the throughput sparkline (one per cell, up to the width).
+const THROUGHPUT_MIN_DAYS: usize = 7;
+const THROUGHPUT_MAX_DAYS: usize = 90;
+/// Issues listed in the oldest-open panel.
+const OLDEST_OPEN: usize = 10;
+
+pub fn render_dashboard(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(9), // Counts
+            Constraint::Length(6), // Throughput
+            Constraint::Min(5),    // Cycle time + oldest open
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    let counts = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(33),
+            Constraint::Percentage(34),
+            Constraint::Percentage(33),
+        ])
+        .split(chunks[0]);
+    render_status_counts(f, counts[0], app);
+    render_priority_counts(f, counts[1], app);
+    render_type_counts(f, counts[2], app);
+
+    render_throughput(f, chunks[1], app);
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(32), Constraint::Min(0)])
+        .split(chunks[2]);
+    render_cycle_time(f, bottom[0], app);
+    render_oldest_open(f, bottom[1], app);
+
+    render_dashboard_footer(f, chunks[3]);
+}
+
+fn panel(title: &str) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" {} ", title))
+        .border_style(Style::default().fg(COLOR_BORDER))
+}
+
+/// One line per row: label, proportional bar and count.
+fn count_lines(rows: Vec<(String, usize, Color)>, width: u16) -> Vec<Line<'static>> {
+    let max = rows.iter().map(|(_, count, _)| *count).max().unwrap_or(0).max(1);
+    let bar_width = (width as usize).saturating_sub(18);
+    rows.into_iter()
+        .map(|(label, count, color)| {
+            let filled = count * bar_width / max;
+            Line::from(vec![
+                Span::styled(format!("{:<12}", label), Style::default().fg(color)),
+                Span::styled("■".repeat(filled), Style::default().fg(color)),
+                Span::styled(format!(" {}", count), Style::default().add_modifier(Modifier::BOLD)),
+            ])
+        })
+        .collect()
+}
+
+fn render_status_counts(f: &mut Frame, area: Rect, app: &App) {
+    let statuses = [
+        Status::Open,
+        Status::InProgress,
+        Status::Blocked,
+        Status::Deferred,
+        Status::Closed,
+    ];
+    let rows = statuses
+        .iter()
+        .map(|&status| {
+            let count = app.issues.iter().filter(|i| i.status == status).count();
+            (status.to_string(), count, status_color(status))
+        })
+        .collect();
+    let block = panel("By status");
+    let lines = count_lines(rows, block.inner(area).width);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_priority_counts(f: &mut Frame, area: Rect, app: &App) {
+    let bars: Vec<Bar> = (0..=4u8)
+        .map(|p| {
+            let count = app.issues.iter().filter(|i| i.priority.0 == p).count();
+            Bar::default()
+                .value(count as u64)
+                .label(Line::from(format!("P{}", p)))
+                .style(Style::default().fg(priority_color(p)))
+                .value_style(
+                    Style::default()
+                        .fg(COLOR_HEADER_BG)
+                        .bg(priority_color(p))
+                        .add_modifier(Modifier::BOLD),
+                )
+        })
+        .collect();
+    let block = panel("By priority");
+    let bar_width = (block.inner(area).width / 5).saturating_sub(1).max(1);
+    let chart = BarChart::default()
+        .block(block)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1);
+    f.render_widget(chart, area);
+}
+
+fn render_type_counts(f: &mut Frame, area: Rect, app: &App) {
+    let types = [
+        IssueType::Task,
+        IssueType::Bug,
+        IssueType::Feature,
+        IssueType::Epic,
+        IssueType::Chore,
+        IssueType::Other,
+    ];
+    let rows = types
+        .iter()
+        .map(|issue_type| {
+            let count = app.issues.iter().filter(|i| &i.issue_type == issue_type).count();
+            (format!("{} {}", issue_type.icon(), issue_type), count, COLOR_HEADER)
+        })
+        .collect();
+    let block = panel("By type");
+    let lines = count_lines(rows, block.inner(area).width);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_throughput(f: &mut Frame, area: Rect, app: &App) {
+    let days = (area.width.saturating_sub(2) as usize).clamp(THROUGHPUT_MIN_DAYS, THROUGHPUT_MAX_DAYS);
+    let per_day = stats::closed_per_day(&app.issues, days, Utc::now());
+    let total: u64 = per_day.iter().sum();
+    let title = format!(
+        "Closed per day · last {} days · {} total · today {}",
+        days,
+        total,
+        per_day.last().copied().unwrap_or(0)
+    );
+    let sparkline = Sparkline::default()
+        .block(panel(&title))
+        .data(&per_day)
+        .style(Style::default().fg(COLOR_DONE));
+    f.render_widget(sparkline, area);
+}
+
+fn render_cycle_time(f: &mut Frame, area: Rect, app: &App) {
+    let cycle_times = stats::cycle_times(&app.issues);
+    let show = |d: Option<chrono::Duration>| d.map(format_duration).unwrap_or_else(|| "-".to_string());
+
+    let label = Style::default().fg(COLOR_SECONDARY_TEXT);
+    let value = Style::default().add_modifier(Modifier::BOLD);
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Average  ", label),
+            Span::styled(show(stats::average(&cycle_times)), value),
+        ]),
+        Line::from(vec![
+            Span::styled("Median   ", label),
+            Span::styled(show(stats::median(&cycle_times)), value),
+        ]),
+        Line::from(vec![
+            Span::styled("Closed   ", label),
+            Span::styled(cycle_times.len().to_string(), value),
+        ]),
+    ];
+    f.render_widget(Paragraph::new(lines).block(panel("Cycle time")), area);
+}
+
+fn render_oldest_open(f: &mut Frame, area: Rect, app: &App) {
+    let lines: Vec<Line> = stats::oldest_open(&app.issues, OLDEST_OPEN)
+        .into_iter()
+        .map(|issue| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>4} ", relative_age(&issue.created_at).unwrap_or_default()),
+                    Style::default().fg(COLOR_BLOCKS),
+                ),
+                Span::styled(
+                    format!("{} ", issue.priority.label()),
+                    Style::default()
+                        .fg(priority_color(issue.priority.0))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{} ", issue.id),
+                    Style::default().fg(status_color(issue.status)),
+                ),
+                Span::raw(issue.title.as_str()),
+            ])
+        })
+        .collect();
+    let title = format!("Oldest open (top {})", OLDEST_OPEN);
+    f.render_widget(Paragraph::new(lines).block(panel(&title)), area);
+}
+
+fn render_dashboard_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Dashboard);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
@@ -1,20 +1,41 @@
+use std::ops::Range;
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{
-        Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+        Block, BorderType, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Wrap,
     },
     Frame,
 };
 use tui_markdown::from_str as markdown_from_str;
 
-use super::app::App;
+use super::app::{App, View};
+use super::keymap;
 use super::theme::*;
 use crate::beads::Issue;
 
-/// Renders the detail view. Returns (scroll_max, viewport_height) for the description area.
-pub fn render_detail(f: &mut Frame, app: &App) -> (u16, u16) {
+/// Scroll extent and issue links of the last rendered description, used by
+/// the detail view's keys.
+#[derive(Debug, Clone, Default)]
+pub struct DetailLayout {
+    pub scroll_max: u16,
+    pub viewport_height: u16,
+    /// Mentions of other loaded issues, in reading order
+    pub links: Vec<DescriptionLink>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DescriptionLink {
+    pub issue_id: String,
+    /// Row of the description it's on, before scrolling
+    pub row: u16,
+}
+
+/// Renders the detail view. Returns the description's scroll extent and links.
+pub fn render_detail(f: &mut Frame, app: &App) -> DetailLayout {
     if let Some(issue) = app.selected_issue() {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -28,12 +49,12 @@ pub fn render_detail(f: &mut Frame, app: &App) -> (u16, u16) {
 
         render_detail_header(f, chunks[0], issue);
         render_detail_metadata(f, chunks[1], issue);
-        let (scroll_max, viewport_height) =
-            render_detail_description(f, chunks[2], issue, app.detail_scroll);
-        render_detail_footer(f, chunks[3], app.detail_scroll, scroll_max);
-        (scroll_max, viewport_height)
+        let layout =
+            render_detail_description(f, chunks[2], app, issue, app.detail_scroll, app.detail_link);
+        render_detail_footer(f, chunks[3], app, layout.scroll_max);
+        layout
     } else {
-        (0, 0)
+        DetailLayout::default()
     }
 }
 
@@ -54,6 +75,19 @@ fn render_detail_header(f: &mut Frame, area: Rect, issue: &Issue) {
 }
 
 fn render_detail_metadata(f: &mut Frame, area: Rect, issue: &Issue) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Metadata ")
+        .style(Style::default());
+
+    let paragraph = Paragraph::new(metadata_lines(issue))
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
+fn metadata_lines(issue: &Issue) -> Vec<Line<'_>> {
     let mut lines = vec![];
 
     // Status and Priority
@@ -97,7 +131,10 @@ fn render_detail_metadata(f: &mut Frame, area: Rect, issue: &Issue) {
                     .fg(COLOR_BLOCKED)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw(format!("{} issue(s)", issue.dependency_count)),
+            Span::raw(format!(
+                "{} issue(s), {} open",
+                issue.dependency_count, issue.open_blocker_count
+            )),
         ]));
     }
 
@@ -124,25 +161,49 @@ fn render_detail_metadata(f: &mut Frame, area: Rect, issue: &Issue) {
         Span::styled(&issue.updated_at, Style::default().fg(COLOR_SECONDARY_TEXT)),
     ]));
 
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .title(" Metadata ")
-        .style(Style::default());
-
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .wrap(Wrap { trim: false });
+    lines
+}
 
-    f.render_widget(paragraph, area);
+/// Compact detail of the selected issue for the board's preview pane. The
+/// description isn't scrollable here; open the detail view for that.
+pub fn render_detail_preview(f: &mut Frame, area: Rect, app: &App) {
+    let Some(issue) = app.selected_issue() else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(" Preview ");
+        let paragraph = Paragraph::new("No issue selected.")
+            .block(block)
+            .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let metadata_height = metadata_lines(issue).len() as u16 + 2;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),               // Header
+            Constraint::Length(metadata_height), // Metadata
+            Constraint::Min(3),                  // Description
+        ])
+        .split(area);
+
+    render_detail_header(f, chunks[0], issue);
+    render_detail_metadata(f, chunks[1], issue);
+    render_detail_description(f, chunks[2], app, issue, 0, None);
 }
 
-/// Renders the description area with scrolling. Returns (scroll_max, viewport_height).
+/// Renders the description area with scrolling, mentions of other issues
+/// styled as links and the `selected` one highlighted.
 fn render_detail_description(
     f: &mut Frame,
     area: Rect,
+    app: &App,
     issue: &Issue,
     scroll: u16,
-) -> (u16, u16) {
+    selected: Option<usize>,
+) -> DetailLayout {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(" Description ")
@@ -177,6 +238,8 @@ fn render_detail_description(
                 Line::from(spans)
             })
             .collect();
+        let (lines, mentions) = linkify(lines, app, &issue.id, selected);
+        let links = link_rows(&lines, mentions, inner_area.width);
 
         let paragraph = Paragraph::new(lines)
             .block(block)
@@ -200,14 +263,130 @@ fn render_detail_description(
             f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
         }
 
-        (scroll_max, viewport_height)
+        DetailLayout {
+            scroll_max,
+            viewport_height,
+            links,
+        }
     } else {
         let paragraph = Paragraph::new("No description available.")
             .block(block)
             .style(Style::default().fg(COLOR_SECONDARY_TEXT));
         f.render_widget(paragraph, area);
-        (0, viewport_height)
+        DetailLayout {
+            viewport_height,
+            ..DetailLayout::default()
+        }
+    }
+}
+
+/// A mention of another issue: its ID, and which line and span show it.
+struct Mention {
+    issue_id: String,
+    line: usize,
+    span: usize,
+}
+
+/// Splits out each mention of another loaded issue's ID into a span of its
+/// own, styled as a link.
+fn linkify(
+    lines: Vec<Line<'static>>,
+    app: &App,
+    own_id: &str,
+    selected: Option<usize>,
+) -> (Vec<Line<'static>>, Vec<Mention>) {
+    let mut mentions = Vec::new();
+    let lines = lines
+        .into_iter()
+        .enumerate()
+        .map(|(line_idx, line)| {
+            let mut spans = Vec::new();
+            for span in line.spans {
+                let text = span.content.as_ref();
+                let ranges = issue_mentions(text, app, own_id);
+                if ranges.is_empty() {
+                    spans.push(span);
+                    continue;
+                }
+                let mut end = 0;
+                for range in ranges {
+                    if range.start > end {
+                        spans.push(Span::styled(text[end..range.start].to_string(), span.style));
+                    }
+                    let mut style = span.style.fg(COLOR_LINK).add_modifier(Modifier::UNDERLINED);
+                    if selected == Some(mentions.len()) {
+                        style = style.bg(COLOR_SELECTED_BG).add_modifier(Modifier::BOLD);
+                    }
+                    mentions.push(Mention {
+                        issue_id: text[range.clone()].to_string(),
+                        line: line_idx,
+                        span: spans.len(),
+                    });
+                    spans.push(Span::styled(text[range.clone()].to_string(), style));
+                    end = range.end;
+                }
+                if end < text.len() {
+                    spans.push(Span::styled(text[end..].to_string(), span.style));
+                }
+            }
+            Line::from(spans)
+        })
+        .collect();
+    (lines, mentions)
+}
+
+/// Byte ranges of `text` naming an issue other than `own_id` that a board tab
+/// can show, so following it works. An ID is a run of letters, digits, `-`,
+/// `_` and `.`, less any trailing punctuation.
+fn issue_mentions(text: &str, app: &App, own_id: &str) -> Vec<Range<usize>> {
+    let is_id_char = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | '.');
+    let mut ranges = Vec::new();
+    let mut rest = text.char_indices().peekable();
+    while let Some((start, c)) = rest.next() {
+        if !is_id_char(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(pos, c)) = rest.peek() {
+            if !is_id_char(c) {
+                break;
+            }
+            end = pos + c.len_utf8();
+            rest.next();
+        }
+        let word = text[start..end].trim_end_matches(['-', '_', '.']);
+        if word != own_id && app.can_reveal(word) {
+            ranges.push(start..start + word.len());
+        }
+    }
+    ranges
+}
+
+/// Where each mention lands once the lines wrap to `width`.
+fn link_rows(lines: &[Line], mentions: Vec<Mention>, width: u16) -> Vec<DescriptionLink> {
+    let wrapped_rows = |line: Line| {
+        Paragraph::new(line)
+            .wrap(Wrap { trim: false })
+            .line_count(width)
+    };
+    let mut line_starts = Vec::with_capacity(lines.len());
+    let mut rows = 0;
+    for line in lines {
+        line_starts.push(rows);
+        rows += wrapped_rows(line.clone());
     }
+    mentions
+        .into_iter()
+        .map(|mention| {
+            // The row the line has wrapped to by the end of the link
+            let upto = Line::from(lines[mention.line].spans[..=mention.span].to_vec());
+            let row = line_starts[mention.line] + wrapped_rows(upto).saturating_sub(1);
+            DescriptionLink {
+                issue_id: mention.issue_id,
+                row: row.min(u16::MAX as usize) as u16,
+            }
+        })
+        .collect()
 }
 
 // Helper function to convert ratatui_core::Color to ratatui::Color
@@ -276,16 +455,15 @@ fn convert_modifier(modifier: ratatui_core::style::Modifier) -> ratatui::style::
     result
 }
 
-fn render_detail_footer(f: &mut Frame, area: Rect, scroll: u16, scroll_max: u16) {
-    let mut parts = vec![
-        Span::styled("[j/k] Scroll  ", Style::default().fg(COLOR_HELP_TEXT)),
-        Span::styled("[g/G] Top/Bottom  ", Style::default().fg(COLOR_HELP_TEXT)),
-        Span::styled("[Esc/q] Back", Style::default().fg(COLOR_HELP_TEXT)),
-    ];
+fn render_detail_footer(f: &mut Frame, area: Rect, app: &App, scroll_max: u16) {
+    let mut parts = vec![match app.status_message {
+        Some(ref msg) => Span::styled(msg.as_str(), Style::default().fg(COLOR_BLOCKED)),
+        None => Span::styled(keymap::footer(View::Detail), Style::default().fg(COLOR_HELP_TEXT)),
+    }];
 
     if scroll_max > 0 {
         parts.push(Span::styled(
-            format!("  [{}/{}]", scroll + 1, scroll_max + 1),
+            format!("  [{}/{}]", app.detail_scroll + 1, scroll_max + 1),
             Style::default().fg(COLOR_SECONDARY_TEXT),
         ));
     }
@@ -0,0 +1,124 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::keymap;
+use super::theme::*;
+use crate::beads::graph::TreeRow;
+use crate::beads::IssueGraph;
+
+/// Width of the inline progress bar, in cells.
+const GAUGE_WIDTH: usize = 10;
+
+pub fn render_epics(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Tree
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    render_epic_tree(f, chunks[0], app);
+    render_epics_footer(f, chunks[1]);
+}
+
+fn render_epic_tree(f: &mut Frame, area: Rect, app: &App) {
+    let graph = IssueGraph::new(&app.issues);
+    let rows = graph.epic_tree(&app.epic_collapsed);
+    let epic_count = rows.iter().filter(|r| r.depth == 0).count();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Epics ({}) ", epic_count))
+        .style(Style::default().fg(COLOR_HEADER));
+
+    if rows.is_empty() {
+        let paragraph = Paragraph::new("No epics found.")
+            .block(block)
+            .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .map(|(idx, row)| format_tree_row(&graph, row, idx == app.epic_selected))
+        .collect();
+
+    let list = List::new(items).block(block);
+    let mut state = ListState::default().with_selected(Some(app.epic_selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn format_tree_row<'a>(graph: &IssueGraph<'a>, row: &TreeRow, is_selected: bool) -> ListItem<'a> {
+    let Some(issue) = graph.issue(&row.issue_id) else {
+        return ListItem::new(row.issue_id.clone());
+    };
+
+    let expander = match (row.has_children, row.expanded) {
+        (true, true) => "▾ ",
+        (true, false) => "▸ ",
+        (false, _) => "  ",
+    };
+
+    let mut spans = vec![
+        Span::raw("  ".repeat(row.depth)),
+        Span::styled(expander, Style::default().fg(COLOR_SECONDARY_TEXT)),
+        Span::styled(
+            format!("{} ", issue.priority.label()),
+            Style::default()
+                .fg(priority_color(issue.priority.0))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} ", issue.id),
+            Style::default().fg(status_color(issue.status)),
+        ),
+        Span::styled(
+            issue.title.as_str(),
+            if row.depth == 0 {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            },
+        ),
+    ];
+
+    if let Some((closed, total)) = row.progress {
+        let filled = (closed * GAUGE_WIDTH).checked_div(total).unwrap_or(0);
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled("█".repeat(filled), Style::default().fg(COLOR_DONE)));
+        spans.push(Span::styled(
+            "░".repeat(GAUGE_WIDTH - filled),
+            Style::default().fg(COLOR_SCROLLBAR_TRACK),
+        ));
+        spans.push(Span::styled(
+            format!(" {}/{}", closed, total),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ));
+    }
+
+    let style = if is_selected {
+        Style::default()
+            .bg(COLOR_SELECTED_BG)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+fn render_epics_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Epics);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
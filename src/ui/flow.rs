@@ -0,0 +1,192 @@
+use chrono::{Duration, NaiveDate, Utc};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+use super::app::{App, FLOW_SPANS, View};
+use super::keymap;
+use super::theme::*;
+use crate::beads::stats::FlowSeries;
+
+/// Partial block characters for the top edge of a band, by eighths filled.
+const LOWER_EIGHTHS: [&str; 8] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇"];
+/// Width of the y-axis labels.
+const Y_AXIS_WIDTH: u16 = 5;
+
+pub fn render_flow(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Range selector
+            Constraint::Min(5),    // Chart
+            Constraint::Length(1), // Legend
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    let today = Utc::now().date_naive();
+    let (start, end) = app.flow_range(today);
+    let series = FlowSeries::build(&app.issues, start, end);
+
+    render_range_selector(f, chunks[0], app, start, end);
+    render_chart(f, chunks[1], app, &series);
+    render_legend(f, chunks[2], app, &series);
+    render_flow_footer(f, chunks[3]);
+}
+
+fn render_range_selector(f: &mut Frame, area: Rect, app: &App, start: NaiveDate, end: NaiveDate) {
+    let mut spans = vec![Span::styled(
+        " Range: ",
+        Style::default().fg(COLOR_SECONDARY_TEXT),
+    )];
+    for (i, span) in FLOW_SPANS.iter().enumerate() {
+        let label = match span {
+            Some(days) => format!(" {}d ", days),
+            None => " All ".to_string(),
+        };
+        let style = if i == app.flow_span {
+            Style::default()
+                .fg(COLOR_HEADER)
+                .bg(COLOR_SELECTED_BG)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(COLOR_HELP_TEXT)
+        };
+        spans.push(Span::styled(label, style));
+    }
+    spans.push(Span::styled(
+        format!(
+            "   {} → {}",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        ),
+        Style::default().fg(COLOR_SECONDARY_TEXT),
+    ));
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_chart(f: &mut Frame, area: Rect, app: &App, series: &FlowSeries) {
+    let title = match app.label_filter {
+        Some(ref label) => format!(" Cumulative Flow · label {} ", label),
+        None => " Cumulative Flow ".to_string(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .style(Style::default().fg(COLOR_HEADER));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let max = series.max_total();
+    if max == 0 || inner.width <= Y_AXIS_WIDTH || inner.height < 2 {
+        let paragraph = Paragraph::new("No issues existed in this range.")
+            .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    // Plot above a row of date labels, right of the count labels
+    let plot = Rect {
+        x: inner.x + Y_AXIS_WIDTH,
+        y: inner.y,
+        width: inner.width - Y_AXIS_WIDTH,
+        height: inner.height - 1,
+    };
+    let axis_style = Style::default().fg(COLOR_SECONDARY_TEXT);
+    let buf = f.buffer_mut();
+    buf.set_string(inner.x, plot.y, format!("{:>4}", max), axis_style);
+    buf.set_string(inner.x, plot.bottom() - 1, format!("{:>4}", 0), axis_style);
+
+    for x in 0..plot.width {
+        let day = x as usize * series.len() / plot.width as usize;
+        let bands = [
+            (series.closed[day], COLOR_DONE),
+            (series.in_progress[day], COLOR_IN_PROGRESS),
+            (series.open[day], COLOR_OPEN),
+        ];
+        draw_stack(buf, plot, plot.x + x, &bands, max);
+    }
+
+    let date = |day: usize| {
+        (series.start + Duration::days(day as i64))
+            .format("%m-%d")
+            .to_string()
+    };
+    let last = series.len() - 1;
+    let labels_y = plot.bottom();
+    buf.set_string(plot.x, labels_y, date(0), axis_style);
+    if plot.width > 20 {
+        let mid = date(last / 2);
+        buf.set_string(plot.x + plot.width / 2 - 2, labels_y, mid, axis_style);
+    }
+    buf.set_string(
+        plot.right().saturating_sub(5),
+        labels_y,
+        date(last),
+        axis_style,
+    );
+}
+
+/// Draws one column of stacked bands, bottom to top, at eighth-cell
+/// resolution. A cell where one band ends takes the band's colour as
+/// foreground and the next band's as background.
+fn draw_stack(buf: &mut Buffer, plot: Rect, x: u16, bands: &[(u64, Color)], max: u64) {
+    let units = plot.height as u64 * 8;
+    let mut tops = Vec::with_capacity(bands.len());
+    let mut total = 0;
+    for &(count, color) in bands {
+        total += count;
+        tops.push(((total * units + max / 2) / max, color));
+    }
+    let band_at = |unit: u64| tops.iter().find(|(top, _)| unit < *top).copied();
+
+    for row in 0..plot.height {
+        let low = row as u64 * 8;
+        let Some((top, color)) = band_at(low) else {
+            break;
+        };
+        let cell = &mut buf[(x, plot.bottom() - 1 - row)];
+        let filled = top.min(low + 8) - low;
+        if filled == 8 {
+            cell.set_symbol("█").set_fg(color);
+        } else {
+            cell.set_symbol(LOWER_EIGHTHS[filled as usize])
+                .set_fg(color);
+            if let Some((_, above)) = band_at(top) {
+                cell.set_bg(above);
+            }
+        }
+    }
+}
+
+fn render_legend(f: &mut Frame, area: Rect, app: &App, series: &FlowSeries) {
+    let last = series.len() - 1;
+    let label = Style::default().fg(COLOR_SECONDARY_TEXT);
+    let source = if app.issues.iter().any(|i| !i.status_history.is_empty()) {
+        "from event history"
+    } else {
+        "inferred from timestamps"
+    };
+    let line = Line::from(vec![
+        Span::styled(" █ ", Style::default().fg(COLOR_OPEN)),
+        Span::styled(format!("Open {}", series.open[last]), label),
+        Span::styled("  █ ", Style::default().fg(COLOR_IN_PROGRESS)),
+        Span::styled(format!("In progress {}", series.in_progress[last]), label),
+        Span::styled("  █ ", Style::default().fg(COLOR_DONE)),
+        Span::styled(format!("Closed {}", series.closed[last]), label),
+        Span::styled(format!("   ({})", source), label),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+fn render_flow_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Flow);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,234 @@
+use chrono::{DateTime, Duration, Utc};
+use ratatui::{style::Style, text::Span};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::beads::models::parse_timestamp;
+
+/// Formats a timestamp as a compact age relative to now, e.g. "5m", "3h", "2d".
+pub fn relative_age(timestamp: &str) -> Option<String> {
+    let then = parse_timestamp(timestamp)?;
+    Some(format_age(Utc::now(), then))
+}
+
+fn format_age(now: DateTime<Utc>, then: DateTime<Utc>) -> String {
+    let secs = (now - then).num_seconds().max(0);
+    let mins = secs / 60;
+    let hours = mins / 60;
+    let days = hours / 24;
+
+    if mins < 1 {
+        "now".to_string()
+    } else if hours < 1 {
+        format!("{}m", mins)
+    } else if days < 1 {
+        format!("{}h", hours)
+    } else if days < 14 {
+        format!("{}d", days)
+    } else if days < 60 {
+        format!("{}w", days / 7)
+    } else if days < 365 {
+        format!("{}mo", days / 30)
+    } else {
+        format!("{}y", days / 365)
+    }
+}
+
+/// Formats a duration with its two most significant units, e.g. "3d 4h".
+pub fn format_duration(duration: Duration) -> String {
+    let mins = duration.num_minutes().max(0);
+    let (days, hours, mins) = (mins / (60 * 24), mins / 60 % 24, mins % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+/// Word-wraps `text` to lines of at most `width` terminal columns, so wide
+/// characters such as CJK and emoji take two. Words wider than a line are
+/// hard-split.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        let mut word = word;
+
+        // Hard-split words that can never fit on a line
+        while word.width() > width {
+            if current_width > 0 {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            let head = split_at_width(word, width);
+            lines.push(head.to_string());
+            word = &word[head.len()..];
+        }
+        let word_width = word.width();
+
+        let sep = if current_width > 0 { 1 } else { 0 };
+        if current_width + sep + word_width > width {
+            lines.push(std::mem::take(&mut current));
+            current_width = 0;
+        } else if sep == 1 {
+            current.push(' ');
+            current_width += 1;
+        }
+        current_width += word_width;
+        current.push_str(word);
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// The longest prefix of `word` that fits in `width` columns. Always at least
+/// one character, so a wide one can't stall the wrap.
+fn split_at_width(word: &str, width: usize) -> &str {
+    let mut end = 0;
+    let mut used = 0;
+    for (i, c) in word.char_indices() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width && i > 0 {
+            break;
+        }
+        end = i + c.len_utf8();
+        used += w;
+    }
+    &word[..end]
+}
+
+/// Splits text into spans, styling the characters at the given (char)
+/// indices, e.g. the positions of a fuzzy match.
+pub fn highlight_matches(
+    text: &str,
+    indices: &[usize],
+    base: Style,
+    matched: Style,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_match = indices.contains(&i);
+        if is_match != run_matched && !run.is_empty() {
+            let style = if run_matched { matched } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = is_match;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        let style = if run_matched { matched } else { base };
+        spans.push(Span::styled(run, style));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-18T12:00:00Z".parse().unwrap()
+    }
+
+    fn age(ago: Duration) -> String {
+        format_age(now(), now() - ago)
+    }
+
+    fn widths(lines: &[String]) -> Vec<usize> {
+        lines.iter().map(|line| line.width()).collect()
+    }
+
+    #[test]
+    fn wrap_breaks_between_words() {
+        assert_eq!(
+            wrap_text("fix the login redirect", 10),
+            ["fix the", "login", "redirect"]
+        );
+    }
+
+    #[test]
+    fn wrap_collapses_whitespace() {
+        assert_eq!(wrap_text("  one \t two\nthree ", 20), ["one two three"]);
+    }
+
+    #[test]
+    fn wrap_of_empty_text_is_one_empty_line() {
+        assert_eq!(wrap_text("", 10), [""]);
+        assert_eq!(wrap_text("   ", 10), [""]);
+    }
+
+    #[test]
+    fn wrap_hard_splits_long_words() {
+        assert_eq!(wrap_text("ab abcdefghij", 4), ["ab", "abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn wrap_treats_zero_width_as_one() {
+        assert_eq!(wrap_text("ab", 0), ["a", "b"]);
+    }
+
+    #[test]
+    fn wrap_measures_wide_characters_in_columns() {
+        // Each of these takes two columns
+        let lines = wrap_text("修复 登录 重定向", 8);
+        assert_eq!(lines, ["修复", "登录", "重定向"]);
+        assert_eq!(widths(&lines), [4, 4, 6]);
+
+        let lines = wrap_text("🚀🚀🚀 ship", 7);
+        assert_eq!(lines, ["🚀🚀🚀", "ship"]);
+    }
+
+    #[test]
+    fn wrap_hard_splits_wide_words_on_column_boundaries() {
+        let lines = wrap_text("重定向问题", 5);
+        assert_eq!(lines, ["重定", "向问", "题"]);
+        assert!(widths(&lines).iter().all(|&w| w <= 5));
+    }
+
+    #[test]
+    fn wrap_puts_a_wide_character_on_its_own_line_when_narrower() {
+        assert_eq!(wrap_text("修复", 1), ["修", "复"]);
+    }
+
+    #[test]
+    fn wrap_keeps_combining_marks_with_their_letter() {
+        let lines = wrap_text("cafe\u{301} ok", 6);
+        assert_eq!(lines, ["cafe\u{301}", "ok"]);
+    }
+
+    #[test]
+    fn age_picks_the_largest_fitting_unit() {
+        assert_eq!(age(Duration::seconds(30)), "now");
+        assert_eq!(age(Duration::minutes(5)), "5m");
+        assert_eq!(age(Duration::minutes(59)), "59m");
+        assert_eq!(age(Duration::hours(3)), "3h");
+        assert_eq!(age(Duration::days(2)), "2d");
+        assert_eq!(age(Duration::days(13)), "13d");
+        assert_eq!(age(Duration::days(21)), "3w");
+        assert_eq!(age(Duration::days(90)), "3mo");
+        assert_eq!(age(Duration::days(800)), "2y");
+    }
+
+    #[test]
+    fn age_switches_units_at_the_boundaries() {
+        assert_eq!(age(Duration::minutes(60)), "1h");
+        assert_eq!(age(Duration::hours(24)), "1d");
+        assert_eq!(age(Duration::days(14)), "2w");
+        assert_eq!(age(Duration::days(60)), "2mo");
+        assert_eq!(age(Duration::days(365)), "1y");
+    }
+
+    #[test]
+    fn future_timestamps_read_as_now() {
+        assert_eq!(age(Duration::hours(-2)), "now");
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::keymap;
+use super::theme::*;
+use crate::beads::IssueGraph;
+
+/// Width of a node label, in cells.
+const NODE_WIDTH: usize = 26;
+/// Horizontal space between layers, where edges are routed.
+const LAYER_GAP: usize = 8;
+/// Rows per node slot (one for the node, one blank between nodes).
+const ROW_SPACING: usize = 2;
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// A node in the layered layout. Dummy nodes (no issue) carry edges that span
+/// more than one layer.
+#[derive(Debug, Clone)]
+pub struct DagNode {
+    pub issue_id: Option<String>,
+    pub layer: usize,
+    pub pos: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphMove {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Layered drawing of the blocking graph: blockers to the left, the issues
+/// they block to the right.
+#[derive(Debug, Clone, Default)]
+pub struct DagLayout {
+    pub nodes: Vec<DagNode>,
+    pub layers: Vec<Vec<usize>>,
+    /// Edges between nodes in adjacent layers.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl DagLayout {
+    pub fn build(graph: &IssueGraph, ids: &[&str]) -> Self {
+        let index: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let preds: Vec<Vec<usize>> = ids
+            .iter()
+            .map(|id| {
+                graph
+                    .blockers(id)
+                    .iter()
+                    .filter_map(|b| index.get(b).copied())
+                    .collect()
+            })
+            .collect();
+
+        // Longest-path layering; edges closing a cycle are dropped
+        let mut layer_of = vec![None; ids.len()];
+        let mut on_stack = vec![false; ids.len()];
+        for i in 0..ids.len() {
+            assign_layer(i, &preds, &mut layer_of, &mut on_stack);
+        }
+
+        let mut layout = DagLayout::default();
+        for (i, id) in ids.iter().enumerate() {
+            layout.push_node(Some(id.to_string()), layer_of[i].unwrap_or(0));
+        }
+
+        // Split long edges into chains of dummy nodes
+        for (to, blockers) in preds.iter().enumerate() {
+            for &from in blockers {
+                let (from_layer, to_layer) = (layout.nodes[from].layer, layout.nodes[to].layer);
+                if from_layer >= to_layer {
+                    continue;
+                }
+                let mut prev = from;
+                for layer in from_layer + 1..to_layer {
+                    let dummy = layout.push_node(None, layer);
+                    layout.edges.push((prev, dummy));
+                    prev = dummy;
+                }
+                layout.edges.push((prev, to));
+            }
+        }
+
+        layout.order_layers();
+        layout
+    }
+
+    fn push_node(&mut self, issue_id: Option<String>, layer: usize) -> usize {
+        let idx = self.nodes.len();
+        if self.layers.len() <= layer {
+            self.layers.resize(layer + 1, Vec::new());
+        }
+        self.nodes.push(DagNode {
+            issue_id,
+            layer,
+            pos: self.layers[layer].len(),
+        });
+        self.layers[layer].push(idx);
+        idx
+    }
+
+    /// Barycenter heuristic: one sweep down the layers by predecessor
+    /// positions, one sweep back up by successor positions.
+    fn order_layers(&mut self) {
+        let mut preds = vec![Vec::new(); self.nodes.len()];
+        let mut succs = vec![Vec::new(); self.nodes.len()];
+        for &(from, to) in &self.edges {
+            preds[to].push(from);
+            succs[from].push(to);
+        }
+
+        let layer_count = self.layers.len();
+        let down = (1..layer_count).map(|l| (l, &preds));
+        let up = (0..layer_count.saturating_sub(1)).rev().map(|l| (l, &succs));
+        for (layer, neighbors) in down.chain(up).collect::<Vec<_>>() {
+            let nodes = &self.nodes;
+            let barycenter = |n: usize| {
+                let adjacent = &neighbors[n];
+                if adjacent.is_empty() {
+                    nodes[n].pos as f64
+                } else {
+                    adjacent.iter().map(|&a| nodes[a].pos as f64).sum::<f64>() / adjacent.len() as f64
+                }
+            };
+            let mut order = self.layers[layer].clone();
+            order.sort_by(|&a, &b| barycenter(a).total_cmp(&barycenter(b)));
+            for (pos, &n) in order.iter().enumerate() {
+                self.nodes[n].pos = pos;
+            }
+            self.layers[layer] = order;
+        }
+    }
+
+    pub fn find(&self, issue_id: &str) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|n| n.issue_id.as_deref() == Some(issue_id))
+    }
+
+    /// The nearest real node in the given direction.
+    pub fn neighbor(&self, from: usize, direction: GraphMove) -> Option<usize> {
+        let node = &self.nodes[from];
+        let real = |n: &&usize| self.nodes[**n].issue_id.is_some();
+        match direction {
+            GraphMove::Up => self.layers[node.layer][..node.pos].iter().rev().find(real),
+            GraphMove::Down => self.layers[node.layer][node.pos + 1..].iter().find(real),
+            GraphMove::Left | GraphMove::Right => {
+                let layers: Vec<usize> = if direction == GraphMove::Left {
+                    (0..node.layer).rev().collect()
+                } else {
+                    (node.layer + 1..self.layers.len()).collect()
+                };
+                layers.into_iter().find_map(|l| {
+                    self.layers[l]
+                        .iter()
+                        .filter(real)
+                        .min_by_key(|&&n| self.nodes[n].pos.abs_diff(node.pos))
+                })
+            }
+        }
+        .copied()
+    }
+
+    fn origin(&self, node: usize) -> (usize, usize) {
+        let node = &self.nodes[node];
+        (node.layer * (NODE_WIDTH + LAYER_GAP), node.pos * ROW_SPACING)
+    }
+}
+
+fn assign_layer(
+    i: usize,
+    preds: &[Vec<usize>],
+    layer_of: &mut [Option<usize>],
+    on_stack: &mut [bool],
+) -> Option<usize> {
+    if let Some(layer) = layer_of[i] {
+        return Some(layer);
+    }
+    if on_stack[i] {
+        return None;
+    }
+    on_stack[i] = true;
+    let layer = preds[i]
+        .iter()
+        .filter_map(|&p| assign_layer(p, preds, layer_of, on_stack))
+        .map(|l| l + 1)
+        .max()
+        .unwrap_or(0);
+    on_stack[i] = false;
+    layer_of[i] = Some(layer);
+    Some(layer)
+}
+
+/// Builds the layout for the graph view's current mode: the whole project, or
+/// the neighborhood of the focused issue.
+pub fn graph_layout(app: &App) -> DagLayout {
+    let graph = IssueGraph::new(&app.issues);
+    let ids = if app.graph_whole {
+        graph.connected_ids()
+    } else if let Some(ref focus) = app.graph_focus {
+        graph.neighborhood(focus, app.graph_depth)
+    } else {
+        Vec::new()
+    };
+    DagLayout::build(&graph, &ids)
+}
+
+pub fn render_graph(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Graph
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    render_graph_canvas(f, chunks[0], app);
+    render_graph_footer(f, chunks[1]);
+}
+
+fn render_graph_canvas(f: &mut Frame, area: Rect, app: &App) {
+    let title = if app.graph_whole {
+        " Dependency Graph · whole project ".to_string()
+    } else {
+        format!(
+            " Dependency Graph · {} · depth {} ",
+            app.graph_focus.as_deref().unwrap_or("-"),
+            app.graph_depth
+        )
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .style(Style::default().fg(COLOR_HEADER));
+    let inner = block.inner(area);
+
+    let layout = app.graph_layout();
+    if layout.nodes.is_empty() {
+        let paragraph = Paragraph::new("No blocking dependencies found.")
+            .block(block)
+            .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let selected = app.graph_selected.as_deref().and_then(|id| layout.find(id));
+    let lines = draw_dag(app, layout, selected);
+
+    // Keep the selected node roughly centred
+    let (scroll_x, scroll_y) = selected
+        .map(|n| {
+            let (x, y) = layout.origin(n);
+            (
+                (x + NODE_WIDTH / 2).saturating_sub(inner.width as usize / 2),
+                y.saturating_sub(inner.height as usize / 2),
+            )
+        })
+        .unwrap_or((0, 0));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((scroll_y.min(u16::MAX as usize) as u16, scroll_x.min(u16::MAX as usize) as u16));
+    f.render_widget(paragraph, area);
+}
+
+/// Draws nodes and box-drawing edges onto a character grid.
+fn draw_dag<'a>(app: &'a App, layout: &DagLayout, selected: Option<usize>) -> Vec<Line<'a>> {
+    let width = layout.layers.len() * (NODE_WIDTH + LAYER_GAP);
+    let height = layout.layers.iter().map(Vec::len).max().unwrap_or(0) * ROW_SPACING;
+    let mut bits = vec![vec![0u8; width]; height];
+    let mut arrows = Vec::new();
+
+    for &(from, to) in &layout.edges {
+        let (from_x, from_y) = layout.origin(from);
+        let (to_x, to_y) = layout.origin(to);
+        let start = from_x + NODE_WIDTH;
+        let end = to_x - 1;
+        // Stagger vertical segments by source so unrelated edges don't merge
+        let mid = start + 1 + layout.nodes[from].pos % (LAYER_GAP - 3);
+
+        for x in start..mid {
+            bits[from_y][x] |= RIGHT;
+            bits[from_y][x + 1] |= LEFT;
+        }
+        let (top, bottom) = (from_y.min(to_y), from_y.max(to_y));
+        for row in bits.iter_mut().take(bottom).skip(top) {
+            row[mid] |= DOWN;
+        }
+        for row in bits.iter_mut().take(bottom + 1).skip(top + 1) {
+            row[mid] |= UP;
+        }
+        for x in mid..end {
+            bits[to_y][x] |= RIGHT;
+            bits[to_y][x + 1] |= LEFT;
+        }
+        if layout.nodes[to].issue_id.is_some() {
+            arrows.push((end, to_y));
+        } else {
+            bits[to_y][end] |= RIGHT;
+        }
+    }
+
+    // Dummy nodes are a straight line through their slot
+    for (n, node) in layout.nodes.iter().enumerate() {
+        if node.issue_id.is_none() {
+            let (x, y) = layout.origin(n);
+            for cell in &mut bits[y][x..x + NODE_WIDTH] {
+                *cell |= LEFT | RIGHT;
+            }
+        }
+    }
+
+    let mut grid: Vec<Vec<(char, Style)>> = bits
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&b| (line_char(b), Style::default().fg(COLOR_BORDER)))
+                .collect()
+        })
+        .collect();
+    for (x, y) in arrows {
+        grid[y][x].0 = '▶';
+    }
+
+    for (n, node) in layout.nodes.iter().enumerate() {
+        let Some(issue) = node.issue_id.as_deref().and_then(|id| app.issue(id)) else {
+            continue;
+        };
+        let (x, y) = layout.origin(n);
+        let mut style = Style::default().fg(status_color(issue.status));
+        if app.graph_focus.as_deref() == Some(issue.id.as_str()) {
+            style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        }
+        if selected == Some(n) {
+            style = style.bg(COLOR_SELECTED_BG).add_modifier(Modifier::BOLD);
+        }
+        let label = format!("{} {} {}", issue.priority.label(), issue.id, issue.title);
+        let label: Vec<char> = label.chars().chain(std::iter::repeat(' ')).take(NODE_WIDTH).collect();
+        for (i, c) in label.into_iter().enumerate() {
+            grid[y][x + i] = (c, style);
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| {
+            let mut spans: Vec<Span> = Vec::new();
+            let mut text = String::new();
+            let mut current = Style::default();
+            for (c, style) in row {
+                if style != current && !text.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut text), current));
+                }
+                current = style;
+                text.push(c);
+            }
+            spans.push(Span::styled(text, current));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn line_char(bits: u8) -> char {
+    match bits {
+        0 => ' ',
+        b if b == UP || b == DOWN || b == UP | DOWN => '│',
+        b if b == LEFT || b == RIGHT || b == LEFT | RIGHT => '─',
+        b if b == DOWN | RIGHT => '┌',
+        b if b == DOWN | LEFT => '┐',
+        b if b == UP | RIGHT => '└',
+        b if b == UP | LEFT => '┘',
+        b if b == UP | DOWN | RIGHT => '├',
+        b if b == UP | DOWN | LEFT => '┤',
+        b if b == LEFT | RIGHT | DOWN => '┬',
+        b if b == LEFT | RIGHT | UP => '┴',
+        _ => '┼',
+    }
+}
+
+fn render_graph_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Graph);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,70 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::keymap;
+use super::theme::*;
+
+/// Renders the `?` overlay listing every binding of the current view.
+pub fn render_help(f: &mut Frame, app: &App) {
+    let bindings = keymap::current_bindings(app);
+    let key_width = bindings
+        .iter()
+        .map(|b| b.keys_label().chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let lines: Vec<Line> = bindings
+        .iter()
+        .map(|b| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:>width$}  ", b.keys_label(), width = key_width),
+                    Style::default()
+                        .fg(COLOR_HEADER)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(b.help),
+            ])
+        })
+        .collect();
+
+    let title = match app.current_view {
+        View::Board if app.board_filter.is_some() => "Board filter",
+        View::Board if app.activity_has_keys() => "Activity feed",
+        view => view.name(),
+    };
+    let content_width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 2;
+    let area = centered(f.area(), content_width + 2, lines.len() as u16 + 2);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Keys: {} ", title))
+        .title_bottom(" any key to close ")
+        .style(Style::default().fg(COLOR_BORDER_ACTIVE));
+
+    let max_scroll = (lines.len() as u16).saturating_sub(area.height.saturating_sub(2));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(Style::default().fg(COLOR_HELP_TEXT))
+        .scroll((app.help_scroll.min(max_scroll), 0));
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// A rect of at most the given size, centred in `area`.
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
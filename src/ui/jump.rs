@@ -0,0 +1,167 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::app::App;
+use super::format::highlight_matches;
+use super::keymap;
+use super::theme::*;
+use crate::beads::models::Issue;
+
+/// Input and selection of the open jump-to-issue prompt.
+#[derive(Debug, Clone, Default)]
+pub struct JumpState {
+    pub input: String,
+    pub selected: usize,
+}
+
+impl JumpState {
+    /// The typed ID, as IDs are compared.
+    fn pattern(&self) -> String {
+        self.input.trim().to_lowercase()
+    }
+}
+
+/// Issues whose ID contains the typed text, IDs starting with it first, then
+/// shortest first so a full ID comes before its children.
+pub fn jump_matches(app: &App) -> Vec<&Issue> {
+    let pattern = app.jump.as_ref().map_or(String::new(), JumpState::pattern);
+    let mut matches: Vec<(bool, &Issue)> = app
+        .issues
+        .iter()
+        .filter_map(|issue| {
+            let id = issue.id.to_lowercase();
+            if id.starts_with(&pattern) {
+                Some((true, issue))
+            } else if id.contains(&pattern) {
+                Some((false, issue))
+            } else {
+                None
+            }
+        })
+        .collect();
+    matches.sort_by(|(a_prefix, a), (b_prefix, b)| {
+        (!a_prefix, a.id.len(), &a.id).cmp(&(!b_prefix, b.id.len(), &b.id))
+    });
+    matches.into_iter().map(|(_, issue)| issue).collect()
+}
+
+/// What Tab completes the input to: the start every matching ID shares, when
+/// that's more than was typed, otherwise the selected match's ID.
+pub fn completion(app: &App) -> Option<String> {
+    let jump = app.jump.as_ref()?;
+    let pattern = jump.pattern();
+    let matches = jump_matches(app);
+    let selected = matches.get(jump.selected)?;
+    let common = matches.iter().fold(selected.id.as_str(), |common, issue| {
+        common_prefix(common, &issue.id)
+    });
+    if common.len() > pattern.len() && common.to_lowercase().contains(&pattern) {
+        Some(common.to_string())
+    } else {
+        Some(selected.id.clone())
+    }
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    &a[..len]
+}
+
+/// Renders the jump-to-issue prompt over the current view.
+pub fn render_jump(f: &mut Frame, app: &App) {
+    let Some(ref jump) = app.jump else {
+        return;
+    };
+    let area = f.area();
+    let width = area.width.saturating_sub(4).min(80);
+    let height = area.height.saturating_sub(2).min(14);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 4,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Go to issue ")
+        .title_bottom(format!(" {} ", keymap::jump_footer()))
+        .style(Style::default().fg(COLOR_SEARCH_BORDER));
+    let inner = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Input
+            Constraint::Min(0),    // Matching issues
+        ])
+        .split(inner);
+
+    let input = Paragraph::new(format!("# {}_", jump.input))
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    f.render_widget(input, chunks[0]);
+
+    let matches = jump_matches(app);
+    if matches.is_empty() {
+        let empty =
+            Paragraph::new("No issue ID matches.").style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(empty, chunks[1]);
+        return;
+    }
+    let selected = jump.selected.min(matches.len() - 1);
+    let pattern = jump.pattern();
+    let match_style = Style::default()
+        .fg(COLOR_SEARCH_MATCH)
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    // Only as many as the list can scroll to
+    let shown = selected + chunks[1].height as usize + 1;
+    let items: Vec<ListItem> = matches
+        .iter()
+        .take(shown)
+        .map(|issue| {
+            let mut spans = highlight_matches(
+                &issue.id,
+                &id_match_indices(&issue.id, &pattern),
+                Style::default().fg(COLOR_HEADER),
+                match_style,
+            );
+            spans.push(Span::raw("  "));
+            spans.push(Span::raw(issue.title.clone()));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .style(Style::default().fg(COLOR_HELP_TEXT))
+        .highlight_style(
+            Style::default()
+                .bg(COLOR_SELECTED_BG)
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut state = ListState::default().with_selected(Some(selected));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
+
+/// Char indices of the first place the typed text appears in an ID.
+fn id_match_indices(id: &str, pattern: &str) -> Vec<usize> {
+    let lowered = id.to_lowercase();
+    let Some(at) = lowered.find(pattern).filter(|_| !pattern.is_empty()) else {
+        return Vec::new();
+    };
+    let start = lowered[..at].chars().count();
+    (start..start + pattern.chars().count()).collect()
+}
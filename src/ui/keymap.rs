@@ -0,0 +1,492 @@
+//! Key bindings for every view. The key handlers, the footers and the `?`
+//! help overlay all read this table, so they can't disagree.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::app::{App, View};
+
+/// What a key does. Each view's handler decides what an action means there,
+/// e.g. `Left` moves between board columns but pans the flow diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Back,
+    Refresh,
+    Help,
+    Up,
+    Down,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    PageUp,
+    PageDown,
+    /// Open the selection (usually its details)
+    Open,
+    NextTab,
+    PrevTab,
+    /// Digit keys; the handler reads which one
+    SwitchTab,
+    ToggleDensity,
+    TogglePreview,
+    NarrowPreview,
+    WidenPreview,
+    ShowEpics,
+    ShowGraph,
+    ShowReady,
+    ShowBlocked,
+    ShowTable,
+    ShowDashboard,
+    ShowBurndown,
+    ShowFlow,
+    ShowSearch,
+    ToggleCollapse,
+    Focus,
+    Deeper,
+    Shallower,
+    ToggleWholeGraph,
+    OpenRootCause,
+    Sort,
+    /// Digit keys; the handler reads which one
+    ToggleColumn,
+    ToggleBurnMode,
+    NextEpic,
+    ShorterRange,
+    LongerRange,
+    ClearQuery,
+    DeleteChar,
+    /// Printable characters; the handler reads which one
+    InsertChar,
+    OpenPalette,
+    ShowActivity,
+    Filter,
+    OlderQuery,
+    NewerQuery,
+    SaveSearch,
+    NextSearchMode,
+    JumpToIssue,
+    /// Tab completion of typed input
+    Complete,
+    /// Select the issue on the board rather than opening it
+    ShowOnBoard,
+    NextLink,
+    PrevLink,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Key {
+    /// Matches regardless of Shift, so `G` and `?` work however the terminal
+    /// reports them, but not with Ctrl or Alt held.
+    Code(KeyCode),
+    Ctrl(char),
+    /// Any of 1-9
+    Digit,
+    /// Any printable character, for text input
+    Text,
+}
+
+impl Key {
+    fn matches(&self, key: &KeyEvent) -> bool {
+        let plain = !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match (*self, key.code) {
+            (Key::Code(code), pressed) => code == pressed && plain,
+            (Key::Ctrl(c), KeyCode::Char(pressed)) => {
+                c == pressed && key.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            (Key::Digit, KeyCode::Char('1'..='9')) => plain,
+            (Key::Text, KeyCode::Char(_)) => plain,
+            _ => false,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Key::Code(code) => match code {
+                KeyCode::Left => "←".to_string(),
+                KeyCode::Right => "→".to_string(),
+                KeyCode::Up => "↑".to_string(),
+                KeyCode::Down => "↓".to_string(),
+                KeyCode::Enter => "Enter".to_string(),
+                KeyCode::Esc => "Esc".to_string(),
+                KeyCode::Tab => "Tab".to_string(),
+                KeyCode::BackTab => "Shift+Tab".to_string(),
+                KeyCode::Home => "Home".to_string(),
+                KeyCode::End => "End".to_string(),
+                KeyCode::PageUp => "PgUp".to_string(),
+                KeyCode::PageDown => "PgDn".to_string(),
+                KeyCode::Backspace => "Backspace".to_string(),
+                KeyCode::F(n) => format!("F{}", n),
+                KeyCode::Char(' ') => "Space".to_string(),
+                KeyCode::Char(c) => c.to_string(),
+                other => format!("{:?}", other),
+            },
+            Key::Ctrl(c) => format!("Ctrl+{}", c.to_ascii_uppercase()),
+            Key::Digit => "1-9".to_string(),
+            Key::Text => "Type".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Binding {
+    pub keys: &'static [Key],
+    pub action: Action,
+    /// Description in the help overlay.
+    pub help: &'static str,
+    /// Short label in the footer, if the binding is shown there. Adjacent
+    /// bindings with the same label share one footer entry.
+    pub footer: Option<&'static str>,
+}
+
+impl Binding {
+    pub fn keys_label(&self) -> String {
+        self.keys
+            .iter()
+            .map(Key::label)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+const fn bind(
+    keys: &'static [Key],
+    action: Action,
+    help: &'static str,
+    footer: Option<&'static str>,
+) -> Binding {
+    Binding {
+        keys,
+        action,
+        help,
+        footer,
+    }
+}
+
+const fn ch(c: char) -> Key {
+    Key::Code(KeyCode::Char(c))
+}
+
+const LEFT: Key = Key::Code(KeyCode::Left);
+const RIGHT: Key = Key::Code(KeyCode::Right);
+const UP: Key = Key::Code(KeyCode::Up);
+const DOWN: Key = Key::Code(KeyCode::Down);
+const ENTER: Key = Key::Code(KeyCode::Enter);
+const ESC: Key = Key::Code(KeyCode::Esc);
+const HOME: Key = Key::Code(KeyCode::Home);
+const END: Key = Key::Code(KeyCode::End);
+
+const HELP: Binding = bind(&[ch('?')], Action::Help, "Show this help", Some("Help"));
+const COMMANDS: Binding =
+    bind(&[ch(':'), Key::Ctrl('p')], Action::OpenPalette, "Command palette", None);
+const JUMP: Binding =
+    bind(&[ch('#'), ch('g')], Action::JumpToIssue, "Jump to an issue by ID", None);
+const BACK: Binding = bind(&[ESC, ch('q')], Action::Back, "Back to the board", Some("Back"));
+const REFRESH: Binding = bind(&[ch('r')], Action::Refresh, "Reload issues", Some("Refresh"));
+const SELECT_UP: Binding = bind(&[UP, ch('k')], Action::Up, "Select previous", Some("Select"));
+const SELECT_DOWN: Binding = bind(&[DOWN, ch('j')], Action::Down, "Select next", Some("Select"));
+const DETAILS: Binding = bind(&[ENTER], Action::Open, "Open issue details", Some("Details"));
+
+#[rustfmt::skip]
+static BOARD: &[Binding] = &[
+    bind(&[LEFT, ch('h')], Action::Left, "Previous column", Some("Navigate")),
+    bind(&[RIGHT, ch('l')], Action::Right, "Next column", Some("Navigate")),
+    SELECT_UP,
+    SELECT_DOWN,
+    DETAILS,
+    bind(&[ch('/')], Action::ShowSearch, "Search", Some("Search")),
+    bind(&[Key::Code(KeyCode::Tab)], Action::NextTab, "Next saved view", None),
+    bind(&[Key::Code(KeyCode::BackTab)], Action::PrevTab, "Previous saved view", None),
+    bind(&[Key::Digit], Action::SwitchTab, "Switch to saved view", None),
+    bind(&[ch('d')], Action::ToggleDensity, "Compact/expanded cards", None),
+    bind(&[ch('p')], Action::TogglePreview, "Toggle preview pane", Some("Preview")),
+    bind(&[ch('<')], Action::NarrowPreview, "Narrow preview pane", None),
+    bind(&[ch('>')], Action::WidenPreview, "Widen preview pane", None),
+    bind(&[ch('e')], Action::ShowEpics, "Epic hierarchy", None),
+    bind(&[ch('D')], Action::ShowGraph, "Dependency graph of the selection", None),
+    bind(&[ch('R')], Action::ShowReady, "Ready work", None),
+    bind(&[ch('B')], Action::ShowBlocked, "Blocked work", None),
+    bind(&[ch('t')], Action::ShowTable, "Table view", None),
+    bind(&[ch('s')], Action::ShowDashboard, "Statistics dashboard", None),
+    bind(&[ch('b')], Action::ShowBurndown, "Burndown chart", None),
+    bind(&[ch('f')], Action::ShowFlow, "Cumulative flow diagram", None),
+    bind(&[ch('a')], Action::ShowActivity, "Activity feed", None),
+    bind(&[ch('F')], Action::Filter, "Filter cards in place", Some("Filter")),
+    REFRESH,
+    JUMP,
+    COMMANDS,
+    HELP,
+    bind(&[ch('q'), ESC], Action::Quit, "Quit", Some("Quit")),
+];
+
+#[rustfmt::skip]
+static DETAIL: &[Binding] = &[
+    bind(&[ch('j'), DOWN], Action::Down, "Scroll down", Some("Scroll")),
+    bind(&[ch('k'), UP], Action::Up, "Scroll up", Some("Scroll")),
+    bind(&[ch('g'), HOME], Action::Top, "Scroll to top", Some("Top/Bottom")),
+    bind(&[ch('G'), END], Action::Bottom, "Scroll to bottom", Some("Top/Bottom")),
+    bind(&[Key::Code(KeyCode::PageDown)], Action::PageDown, "Page down", None),
+    bind(&[Key::Code(KeyCode::PageUp)], Action::PageUp, "Page up", None),
+    bind(&[Key::Code(KeyCode::Tab)], Action::NextLink, "Next issue mentioned in the description", Some("Links")),
+    bind(&[Key::Code(KeyCode::BackTab)], Action::PrevLink, "Previous issue mentioned", Some("Links")),
+    bind(&[ENTER], Action::Open, "Open the selected mention", None),
+    bind(&[ch('#')], Action::JumpToIssue, "Jump to an issue by ID", None),
+    COMMANDS,
+    HELP,
+    bind(&[ESC, ch('q')], Action::Back, "Back to the previous issue or view", Some("Back")),
+];
+
+#[rustfmt::skip]
+static SEARCH: &[Binding] = &[
+    bind(&[Key::Text], Action::InsertChar, "Type to search", Some("Search")),
+    bind(&[UP], Action::Up, "Previous result", Some("Navigate")),
+    bind(&[DOWN], Action::Down, "Next result", Some("Navigate")),
+    bind(&[ENTER], Action::Open, "Open result", Some("View")),
+    bind(&[ESC], Action::Back, "Back to the board", Some("Back")),
+    bind(&[Key::Ctrl('u')], Action::ClearQuery, "Clear the query", Some("Clear")),
+    bind(&[Key::Ctrl('p')], Action::OlderQuery, "Previous query from history", Some("History")),
+    bind(&[Key::Ctrl('n')], Action::NewerQuery, "Next query from history", Some("History")),
+    bind(&[Key::Ctrl('s')], Action::SaveSearch, "Save the query under a name", Some("Save")),
+    bind(&[Key::Code(KeyCode::Tab)], Action::NextSearchMode, "Fuzzy/exact/case-sensitive/regex", Some("Mode")),
+    bind(&[Key::Code(KeyCode::Backspace)], Action::DeleteChar, "Delete a character", None),
+    bind(&[Key::Code(KeyCode::F(1))], Action::Help, "Show this help", Some("Help")),
+];
+
+#[rustfmt::skip]
+static EPICS: &[Binding] = &[
+    SELECT_UP,
+    SELECT_DOWN,
+    bind(&[ch(' ')], Action::ToggleCollapse, "Collapse/expand", Some("Toggle")),
+    bind(&[LEFT, ch('h')], Action::Left, "Collapse, or go to the parent", Some("Collapse/Expand")),
+    bind(&[RIGHT, ch('l')], Action::Right, "Expand", Some("Collapse/Expand")),
+    DETAILS,
+    bind(&[ch('b')], Action::ShowBurndown, "Burndown chart for the epic", None),
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+#[rustfmt::skip]
+static GRAPH: &[Binding] = &[
+    bind(&[UP, ch('k')], Action::Up, "Move up", Some("Move")),
+    bind(&[DOWN, ch('j')], Action::Down, "Move down", Some("Move")),
+    bind(&[LEFT, ch('h')], Action::Left, "Move left (upstream)", Some("Move")),
+    bind(&[RIGHT, ch('l')], Action::Right, "Move right (downstream)", Some("Move")),
+    DETAILS,
+    bind(&[ch('f'), ch(' ')], Action::Focus, "Re-centre on the selection", Some("Focus")),
+    bind(&[ch('+'), ch('=')], Action::Deeper, "Show more hops", Some("Depth")),
+    bind(&[ch('-')], Action::Shallower, "Show fewer hops", Some("Depth")),
+    bind(&[ch('a')], Action::ToggleWholeGraph, "Toggle the whole project", Some("Whole project")),
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+static READY: &[Binding] = &[SELECT_UP, SELECT_DOWN, DETAILS, REFRESH, JUMP, COMMANDS, HELP, BACK];
+
+#[rustfmt::skip]
+static BLOCKED: &[Binding] = &[
+    SELECT_UP,
+    SELECT_DOWN,
+    DETAILS,
+    bind(&[ch('c')], Action::OpenRootCause, "Open the root cause", Some("Root cause details")),
+    REFRESH,
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+#[rustfmt::skip]
+static TABLE: &[Binding] = &[
+    SELECT_UP,
+    SELECT_DOWN,
+    bind(&[HOME], Action::Top, "First row", None),
+    bind(&[END], Action::Bottom, "Last row", None),
+    bind(&[LEFT, ch('h')], Action::Left, "Previous column", Some("Column")),
+    bind(&[RIGHT, ch('l')], Action::Right, "Next column", Some("Column")),
+    bind(&[ch('s')], Action::Sort, "Sort by the column (again to reverse)", Some("Sort")),
+    bind(&[Key::Digit], Action::ToggleColumn, "Show/hide a column", Some("Show/hide")),
+    DETAILS,
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+static DASHBOARD: &[Binding] = &[REFRESH, JUMP, COMMANDS, HELP, BACK];
+
+#[rustfmt::skip]
+static BURNDOWN: &[Binding] = &[
+    bind(&[ch('m')], Action::ToggleBurnMode, "Switch burndown/burn-up", Some("Burndown/burn-up")),
+    bind(&[ch('e')], Action::NextEpic, "Chart the next epic", Some("Next epic")),
+    REFRESH,
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+#[rustfmt::skip]
+static FLOW: &[Binding] = &[
+    bind(&[LEFT, ch('h')], Action::Left, "Pan back in time", Some("Pan")),
+    bind(&[RIGHT, ch('l')], Action::Right, "Pan forward in time", Some("Pan")),
+    bind(&[ch('[')], Action::ShorterRange, "Shorter range", Some("Range")),
+    bind(&[ch(']')], Action::LongerRange, "Longer range", Some("Range")),
+    bind(&[END], Action::Bottom, "Back to today", Some("Today")),
+    REFRESH,
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+/// Board keys while the activity feed has focus.
+#[rustfmt::skip]
+static ACTIVITY: &[Binding] = &[
+    SELECT_UP,
+    SELECT_DOWN,
+    bind(&[ENTER], Action::Open, "Select the issue on the board", Some("Go to issue")),
+    bind(&[ch('a')], Action::ShowActivity, "Hide the feed", Some("Hide")),
+    JUMP,
+    COMMANDS,
+    HELP,
+    BACK,
+];
+
+/// Board keys while typing a live filter.
+#[rustfmt::skip]
+static FILTER: &[Binding] = &[
+    bind(&[Key::Text], Action::InsertChar, "Type to filter the cards", None),
+    bind(&[LEFT], Action::Left, "Previous column", Some("Navigate")),
+    bind(&[RIGHT], Action::Right, "Next column", Some("Navigate")),
+    bind(&[UP], Action::Up, "Select previous", Some("Navigate")),
+    bind(&[DOWN], Action::Down, "Select next", Some("Navigate")),
+    bind(&[ENTER], Action::Open, "View details", Some("Details")),
+    bind(&[ESC], Action::Back, "Clear the filter", Some("Clear")),
+    bind(&[Key::Ctrl('u')], Action::ClearQuery, "Clear the input", None),
+    bind(&[Key::Code(KeyCode::Backspace)], Action::DeleteChar, "Delete a character", None),
+    bind(&[Key::Code(KeyCode::F(1))], Action::Help, "Show this help", Some("Help")),
+];
+
+/// Keys while the command palette is open, over any view.
+#[rustfmt::skip]
+static PALETTE: &[Binding] = &[
+    bind(&[Key::Text], Action::InsertChar, "Type to filter", None),
+    bind(&[UP, Key::Ctrl('p')], Action::Up, "Previous command", Some("Select")),
+    bind(&[DOWN, Key::Ctrl('n')], Action::Down, "Next command", Some("Select")),
+    bind(&[ENTER], Action::Open, "Run the command", Some("Run")),
+    bind(&[ESC], Action::Back, "Close the palette", Some("Close")),
+    bind(&[Key::Ctrl('u')], Action::ClearQuery, "Clear the filter", None),
+    bind(&[Key::Code(KeyCode::Backspace)], Action::DeleteChar, "Delete a character", None),
+];
+
+/// Keys while the jump-to-issue prompt is open, over any view.
+#[rustfmt::skip]
+static JUMP_PROMPT: &[Binding] = &[
+    bind(&[Key::Text], Action::InsertChar, "Type a full or partial issue ID", None),
+    bind(&[UP], Action::Up, "Previous match", Some("Select")),
+    bind(&[DOWN], Action::Down, "Next match", Some("Select")),
+    bind(&[Key::Code(KeyCode::Tab)], Action::Complete, "Complete the ID", Some("Complete")),
+    bind(&[ENTER], Action::ShowOnBoard, "Select the issue on the board", Some("Board")),
+    bind(&[Key::Ctrl('o')], Action::Open, "Open the issue's details", Some("Details")),
+    bind(&[ESC], Action::Back, "Close the prompt", Some("Close")),
+    bind(&[Key::Ctrl('u')], Action::ClearQuery, "Clear the input", None),
+    bind(&[Key::Code(KeyCode::Backspace)], Action::DeleteChar, "Delete a character", None),
+];
+
+pub fn bindings(view: View) -> &'static [Binding] {
+    match view {
+        View::Board => BOARD,
+        View::Detail => DETAIL,
+        View::Search => SEARCH,
+        View::Epics => EPICS,
+        View::Graph => GRAPH,
+        View::Ready => READY,
+        View::Blocked => BLOCKED,
+        View::Table => TABLE,
+        View::Dashboard => DASHBOARD,
+        View::Burndown => BURNDOWN,
+        View::Flow => FLOW,
+    }
+}
+
+/// Bindings for whatever has the keyboard: the board filter while typing
+/// one, the activity feed when it has focus, otherwise the current view.
+pub fn current_bindings(app: &App) -> &'static [Binding] {
+    match app.current_view {
+        View::Board if app.board_filter.is_some() => FILTER,
+        View::Board if app.activity_has_keys() => ACTIVITY,
+        view => bindings(view),
+    }
+}
+
+pub fn palette_action(key: &KeyEvent) -> Option<Action> {
+    action_for(PALETTE, key)
+}
+
+/// The action bound to a key; the first matching binding wins.
+pub fn action_for(bindings: &[Binding], key: &KeyEvent) -> Option<Action> {
+    bindings
+        .iter()
+        .find(|b| b.keys.iter().any(|k| k.matches(key)))
+        .map(|b| b.action)
+}
+
+/// Footer help for a view, e.g. `[↑/↓ or k/j] Select  [Enter] Details`.
+pub fn footer(view: View) -> String {
+    footer_for(bindings(view))
+}
+
+pub fn palette_footer() -> String {
+    footer_for(PALETTE)
+}
+
+pub fn jump_action(key: &KeyEvent) -> Option<Action> {
+    action_for(JUMP_PROMPT, key)
+}
+
+pub fn jump_footer() -> String {
+    footer_for(JUMP_PROMPT)
+}
+
+pub fn footer_for(bindings: &[Binding]) -> String {
+    let mut entries: Vec<(&str, Vec<&Binding>)> = Vec::new();
+    for binding in bindings {
+        let Some(label) = binding.footer else {
+            continue;
+        };
+        match entries.last_mut() {
+            Some((last, group)) if *last == label => group.push(binding),
+            _ => entries.push((label, vec![binding])),
+        }
+    }
+    entries
+        .iter()
+        .map(|(label, group)| format!("[{}] {}", group_keys_label(group), label))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+/// Keys of bindings sharing a footer entry, lined up by position: `↑`/`k`
+/// and `↓`/`j` become `↑/↓ or k/j`. Alternates only some bindings have are
+/// left out.
+fn group_keys_label(group: &[&Binding]) -> String {
+    if let [binding] = group {
+        return binding.keys_label();
+    }
+    let positions = group.iter().map(|b| b.keys.len()).min().unwrap_or(0);
+    (0..positions)
+        .map(|i| {
+            group
+                .iter()
+                .map(|b| b.keys[i].label())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
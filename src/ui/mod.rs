@@ -1,10 +1,35 @@
+pub mod activity;
 pub mod app;
+pub mod blocked;
 pub mod board;
+pub mod burndown;
+pub mod dashboard;
 pub mod detail;
+pub mod epics;
+pub mod flow;
+pub mod format;
+pub mod graph;
+pub mod help;
+pub mod jump;
+pub mod keymap;
+pub mod palette;
+pub mod ready;
 pub mod search;
+pub mod table;
 pub mod theme;
 
 pub use app::{App, View};
+pub use blocked::render_blocked;
 pub use board::render_board;
+pub use burndown::render_burndown;
+pub use dashboard::render_dashboard;
 pub use detail::render_detail;
+pub use epics::render_epics;
+pub use flow::render_flow;
+pub use graph::render_graph;
+pub use help::render_help;
+pub use jump::render_jump;
+pub use palette::render_palette;
+pub use ready::render_ready;
 pub use search::render_search;
+pub use table::render_table;
@@ -0,0 +1,292 @@
+use std::cmp::Reverse;
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::format::highlight_matches;
+use super::keymap::{self, Action};
+use super::table::TableColumn;
+use super::theme::*;
+use crate::beads::query::SearchMode;
+
+/// Something the command palette can run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Show(View),
+    Refresh,
+    /// Reload with a label filter, or without one
+    FilterLabel(Option<String>),
+    /// Order board cards by a column (again to reverse), or bd's order
+    SortBoard(Option<TableColumn>),
+    SwitchTab(usize),
+    TogglePreview,
+    ToggleDensity,
+    ToggleActivity,
+    /// Start filtering the board's cards in place
+    Filter,
+    /// Filter the board's cards by a saved search's query, in its mode
+    FilterBoard(String, SearchMode),
+    /// Select an issue on the board and open its details
+    JumpTo(String),
+    Help,
+    Quit,
+}
+
+impl Command {
+    /// The board key that does the same thing, if any.
+    fn action(&self) -> Option<Action> {
+        let action = match self {
+            Command::Show(View::Epics) => Action::ShowEpics,
+            Command::Show(View::Graph) => Action::ShowGraph,
+            Command::Show(View::Ready) => Action::ShowReady,
+            Command::Show(View::Blocked) => Action::ShowBlocked,
+            Command::Show(View::Table) => Action::ShowTable,
+            Command::Show(View::Dashboard) => Action::ShowDashboard,
+            Command::Show(View::Burndown) => Action::ShowBurndown,
+            Command::Show(View::Flow) => Action::ShowFlow,
+            Command::Show(View::Search) => Action::ShowSearch,
+            Command::Refresh => Action::Refresh,
+            Command::TogglePreview => Action::TogglePreview,
+            Command::ToggleDensity => Action::ToggleDensity,
+            Command::ToggleActivity => Action::ShowActivity,
+            Command::Filter => Action::Filter,
+            Command::Help => Action::Help,
+            Command::Quit => Action::Quit,
+            _ => return None,
+        };
+        Some(action)
+    }
+
+    /// Label of the board key bound to this command, shown as a hint.
+    fn shortcut(&self) -> Option<String> {
+        let action = self.action()?;
+        keymap::bindings(View::Board)
+            .iter()
+            .find(|b| b.action == action)
+            .map(|b| b.keys_label())
+    }
+}
+
+/// Query and selection of the open palette.
+#[derive(Debug, Clone, Default)]
+pub struct PaletteState {
+    pub query: String,
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub title: String,
+    pub command: Command,
+    /// Char indices of the title matching the query
+    pub match_indices: Vec<usize>,
+}
+
+/// Every command available right now, commands before issues.
+fn palette_entries(app: &App) -> Vec<(String, Command)> {
+    let mut entries = Vec::new();
+    for view in [
+        View::Board,
+        View::Search,
+        View::Epics,
+        View::Graph,
+        View::Ready,
+        View::Blocked,
+        View::Table,
+        View::Dashboard,
+        View::Burndown,
+        View::Flow,
+    ] {
+        if view == View::Graph && app.selected_issue().is_none() {
+            continue;
+        }
+        entries.push((format!("Show {}", view.name()), Command::Show(view)));
+    }
+    entries.push(("Filter board".to_string(), Command::Filter));
+    entries.push(("Refresh issues".to_string(), Command::Refresh));
+    entries.push(("Toggle preview pane".to_string(), Command::TogglePreview));
+    entries.push(("Toggle card density".to_string(), Command::ToggleDensity));
+    entries.push(("Toggle activity feed".to_string(), Command::ToggleActivity));
+
+    for (i, tab) in app.tabs.iter().enumerate() {
+        if i != app.current_tab {
+            entries.push((
+                format!("Switch to view: {}", tab.name),
+                Command::SwitchTab(i),
+            ));
+        }
+    }
+
+    for search in &app.search_history.saved {
+        entries.push((
+            format!("Filter board by saved search: {}", search.name),
+            Command::FilterBoard(search.query.clone(), search.mode),
+        ));
+    }
+
+    if app.label_filter.is_some() {
+        entries.push(("Clear label filter".to_string(), Command::FilterLabel(None)));
+    }
+    let mut labels: Vec<&str> = app
+        .issues
+        .iter()
+        .flat_map(|i| i.labels.iter().map(String::as_str))
+        .filter(|l| Some(*l) != app.label_filter.as_deref())
+        .collect();
+    labels.sort_unstable();
+    labels.dedup();
+    for label in labels {
+        entries.push((
+            format!("Filter by label: {}", label),
+            Command::FilterLabel(Some(label.to_string())),
+        ));
+    }
+
+    for column in TableColumn::ALL {
+        entries.push((
+            format!("Sort board by {}", column.name()),
+            Command::SortBoard(Some(column)),
+        ));
+    }
+    if app.tab().sort.is_some() {
+        entries.push((
+            "Sort board in bd order".to_string(),
+            Command::SortBoard(None),
+        ));
+    }
+
+    entries.push(("Show keyboard help".to_string(), Command::Help));
+    entries.push(("Quit".to_string(), Command::Quit));
+
+    for issue in &app.issues {
+        entries.push((
+            format!("Go to {} {}", issue.id, issue.title),
+            Command::JumpTo(issue.id.clone()),
+        ));
+    }
+    entries
+}
+
+/// Entries matching the palette query, best first.
+pub fn palette_matches(app: &App) -> Vec<PaletteEntry> {
+    let query = app.palette.as_ref().map_or("", |p| p.query.as_str());
+    let entries = palette_entries(app).into_iter();
+    if query.is_empty() {
+        return entries
+            .map(|(title, command)| PaletteEntry {
+                title,
+                command,
+                match_indices: Vec::new(),
+            })
+            .collect();
+    }
+
+    let matcher = SkimMatcherV2::default();
+    let mut scored: Vec<(i64, PaletteEntry)> = entries
+        .filter_map(|(title, command)| {
+            let (score, match_indices) = matcher.fuzzy_indices(&title, query)?;
+            Some((
+                score,
+                PaletteEntry {
+                    title,
+                    command,
+                    match_indices,
+                },
+            ))
+        })
+        .collect();
+    // Stable, so equal scores keep commands ahead of issues
+    scored.sort_by_key(|(score, _)| Reverse(*score));
+    scored.into_iter().map(|(_, entry)| entry).collect()
+}
+
+/// Renders the command palette over the current view.
+pub fn render_palette(f: &mut Frame, app: &App) {
+    let Some(ref palette) = app.palette else {
+        return;
+    };
+    let area = f.area();
+    let width = area.width.saturating_sub(4).min(80);
+    let height = area.height.saturating_sub(2).min(20);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 4,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Commands ")
+        .title_bottom(format!(" {} ", keymap::palette_footer()))
+        .style(Style::default().fg(COLOR_SEARCH_BORDER));
+    let inner = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Query
+            Constraint::Min(0),    // Commands
+        ])
+        .split(inner);
+
+    let input = Paragraph::new(format!(": {}_", palette.query))
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    f.render_widget(input, chunks[0]);
+
+    let matches = palette_matches(app);
+    if matches.is_empty() {
+        let empty = Paragraph::new("No matching commands.")
+            .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(empty, chunks[1]);
+        return;
+    }
+    let match_style = Style::default()
+        .fg(COLOR_SEARCH_MATCH)
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|entry| {
+            let mut spans = highlight_matches(
+                &entry.title,
+                &entry.match_indices,
+                Style::default(),
+                match_style,
+            );
+            if let Some(shortcut) = entry.command.shortcut() {
+                // Right-align the key hint
+                let used = entry.title.chars().count() + shortcut.chars().count();
+                let pad = (chunks[1].width as usize).saturating_sub(used + 1);
+                spans.push(Span::raw(" ".repeat(pad)));
+                spans.push(Span::styled(
+                    shortcut,
+                    Style::default().fg(COLOR_SECONDARY_TEXT),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .style(Style::default().fg(COLOR_HELP_TEXT))
+        .highlight_style(
+            Style::default()
+                .bg(COLOR_SELECTED_BG)
+                .fg(COLOR_HEADER)
+                .add_modifier(Modifier::BOLD),
+        );
+    let selected = Some(palette.selected.min(matches.len() - 1));
+    let mut state = ListState::default().with_selected(selected);
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
@@ -0,0 +1,104 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::format::relative_age;
+use super::keymap;
+use super::theme::*;
+use crate::beads::Issue;
+
+pub fn render_ready(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Ready list
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    render_ready_list(f, chunks[0], app);
+    render_ready_footer(f, chunks[1]);
+}
+
+fn render_ready_list(f: &mut Frame, area: Rect, app: &App) {
+    let issues = app.ready_issues();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Ready ({}) ", issues.len()))
+        .style(Style::default().fg(COLOR_DONE));
+
+    if issues.is_empty() {
+        let paragraph = Paragraph::new("No ready work: every open issue is blocked.")
+            .block(block)
+            .style(Style::default().fg(COLOR_SECONDARY_TEXT));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = issues
+        .iter()
+        .enumerate()
+        .map(|(idx, issue)| format_ready_item(idx, issue, idx == app.ready_selected))
+        .collect();
+
+    let list = List::new(items).block(block);
+    let mut state = ListState::default().with_selected(Some(app.ready_selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn format_ready_item(rank: usize, issue: &Issue, is_selected: bool) -> ListItem<'_> {
+    let mut spans = vec![
+        Span::styled(
+            format!("{:>3}. ", rank + 1),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ),
+        Span::styled(
+            format!("{} ", issue.priority.label()),
+            Style::default()
+                .fg(priority_color(issue.priority.0))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} ", issue.id),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ),
+        Span::raw(format!("{} ", issue.issue_type.icon())),
+        Span::raw(issue.title.as_str()),
+    ];
+
+    if !issue.labels.is_empty() {
+        spans.push(Span::styled(
+            format!(" [{}]", issue.labels.join(", ")),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ));
+    }
+    if let Some(age) = relative_age(&issue.created_at) {
+        spans.push(Span::styled(
+            format!(" · {}", age),
+            Style::default().fg(COLOR_SECONDARY_TEXT),
+        ));
+    }
+
+    let style = if is_selected {
+        Style::default()
+            .bg(COLOR_SELECTED_BG)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+fn render_ready_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Ready);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
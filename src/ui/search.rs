@@ -1,15 +1,40 @@
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Position, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
-use super::app::App;
+use super::app::{field_indices, App, FieldMatch, SearchField, View};
+use super::format::highlight_matches;
+use super::keymap;
 use super::theme::*;
+use crate::beads::Issue;
 
-pub fn render_search(f: &mut Frame, app: &App) {
+/// Chars of context kept before the match in a result's snippet line.
+const SNIPPET_CONTEXT: usize = 20;
+
+/// Screen regions of the last rendered results, used for mouse hit-testing.
+#[derive(Debug, Clone, Default)]
+pub struct SearchLayout {
+    /// (result index, screen area) for each visible result
+    pub rows: Vec<(usize, Rect)>,
+}
+
+impl SearchLayout {
+    /// Index of the result at the given screen position, if any.
+    pub fn result_at(&self, x: u16, y: u16) -> Option<usize> {
+        let pos = Position::new(x, y);
+        self.rows
+            .iter()
+            .find(|(_, rect)| rect.contains(pos))
+            .map(|(idx, _)| *idx)
+    }
+}
+
+/// Renders the search view. Returns the results list layout for mouse handling.
+pub fn render_search(f: &mut Frame, app: &App) -> SearchLayout {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -20,26 +45,77 @@ pub fn render_search(f: &mut Frame, app: &App) {
         .split(f.area());
 
     render_search_input(f, chunks[0], app);
-    render_search_results(f, chunks[1], app);
-    render_search_footer(f, chunks[2]);
+    let layout = render_search_results(f, chunks[1], app);
+    render_search_footer(f, chunks[2], app);
+    layout
 }
 
 fn render_search_input(f: &mut Frame, area: Rect, app: &App) {
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(" Search ")
+        .title(
+            Line::styled(
+                format!(" {} ", app.search_mode.label()),
+                Style::default().fg(COLOR_SEARCH_MATCH),
+            )
+            .right_aligned(),
+        )
         .style(Style::default().fg(COLOR_SEARCH_BORDER));
 
-    let input_text = format!("/ {}_", app.search_query);
-    let paragraph = Paragraph::new(input_text)
+    if let Some(ref name) = app.search_naming {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(" Save search ")
+            .title_bottom(" Enter to save, Esc to cancel ")
+            .style(Style::default().fg(COLOR_SEARCH_BORDER));
+        let paragraph = Paragraph::new(format!("Name for `{}`: {}_", app.search_query, name))
+            .block(block)
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        f.render_widget(paragraph, area);
+        return;
+    }
+    if let Some(pos) = app.search_history_pos {
+        block = block.title_bottom(format!(
+            " history {}/{} ",
+            pos + 1,
+            app.search_history.recent.len()
+        ));
+    }
+
+    let mut spans = vec![Span::raw("/ ")];
+    if let Some(ref error) = app.search_error {
+        // Mark the offending term and say what's wrong under it
+        let chars: Vec<char> = app.search_query.chars().collect();
+        let start = error.start.min(chars.len());
+        let end = error.end.clamp(start, chars.len());
+        spans.push(Span::raw(chars[..start].iter().collect::<String>()));
+        spans.push(Span::styled(
+            chars[start..end].iter().collect::<String>(),
+            Style::default()
+                .fg(COLOR_BLOCKED)
+                .add_modifier(Modifier::UNDERLINED),
+        ));
+        spans.push(Span::raw(chars[end..].iter().collect::<String>()));
+        block = block.title_bottom(Line::styled(
+            format!(" {} ", error),
+            Style::default().fg(COLOR_BLOCKED),
+        ));
+    } else {
+        spans.push(Span::raw(app.search_query.as_str()));
+    }
+    spans.push(Span::raw("_"));
+
+    let paragraph = Paragraph::new(Line::from(spans))
         .block(block)
         .style(Style::default().add_modifier(Modifier::BOLD));
 
     f.render_widget(paragraph, area);
 }
 
-fn render_search_results(f: &mut Frame, area: Rect, app: &App) {
+fn render_search_results(f: &mut Frame, area: Rect, app: &App) -> SearchLayout {
     let title = format!(" Results ({}) ", app.search_results.len());
 
     let block = Block::default()
@@ -47,15 +123,22 @@ fn render_search_results(f: &mut Frame, area: Rect, app: &App) {
         .title(title)
         .style(Style::default());
 
-    let items: Vec<ListItem> = app
-        .search_results
+    let list_area = block.inner(area);
+    let snippet_width = list_area.width as usize;
+    // Only build the results that can be on screen: a screenful either side
+    // of the selection covers wherever the list scrolls to
+    let height = list_area.height as usize;
+    let last = (app.search_selected + height + 1).min(app.search_results.len());
+    let first = app.search_selected.saturating_sub(height).min(last);
+    let items: Vec<ListItem> = app.search_results[first..last]
         .iter()
         .enumerate()
-        .map(|(idx, result)| {
-            let is_selected = idx == app.search_selected;
-            let issue = app.issues.iter().find(|i| i.id == result.issue_id);
+        .map(|(offset, result)| {
+            let is_selected = first + offset == app.search_selected;
+            let issue = app.issue(&result.issue_id);
 
             if let Some(issue) = issue {
+                let matches = app.search_matches(result);
                 let priority_clr = priority_color(issue.priority.0);
 
                 let mut spans: Vec<Span> = vec![
@@ -72,32 +155,12 @@ fn render_search_results(f: &mut Frame, area: Rect, app: &App) {
                 ];
 
                 // Build title with match highlighting
-                let title_chars: Vec<char> = issue.title.chars().collect();
-                let mut i = 0;
-                while i < title_chars.len() {
-                    if result.title_match_indices.contains(&i) {
-                        // Collect consecutive matched chars
-                        let start = i;
-                        while i < title_chars.len() && result.title_match_indices.contains(&i) {
-                            i += 1;
-                        }
-                        let matched: String = title_chars[start..i].iter().collect();
-                        spans.push(Span::styled(
-                            matched,
-                            Style::default()
-                                .fg(COLOR_SEARCH_MATCH)
-                                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-                        ));
-                    } else {
-                        // Collect consecutive non-matched chars
-                        let start = i;
-                        while i < title_chars.len() && !result.title_match_indices.contains(&i) {
-                            i += 1;
-                        }
-                        let unmatched: String = title_chars[start..i].iter().collect();
-                        spans.push(Span::raw(unmatched));
-                    }
-                }
+                spans.extend(highlight_matches(
+                    &issue.title,
+                    field_indices(matches, SearchField::Title),
+                    Style::default(),
+                    match_style(),
+                ));
 
                 // Labels
                 if !issue.labels.is_empty() {
@@ -115,7 +178,9 @@ fn render_search_results(f: &mut Frame, area: Rect, app: &App) {
                     Style::default()
                 };
 
-                ListItem::new(Line::from(spans)).style(style)
+                let mut lines = vec![Line::from(spans)];
+                lines.extend(snippet_line(issue, matches, snippet_width));
+                ListItem::new(lines).style(style)
             } else {
                 // Issue was removed — show stale ID
                 let style = if is_selected {
@@ -130,12 +195,91 @@ fn render_search_results(f: &mut Frame, area: Rect, app: &App) {
         })
         .collect();
 
+    let heights: Vec<u16> = items.iter().map(|item| item.height() as u16).collect();
     let list = List::new(items).block(block);
-    f.render_widget(list, area);
+    let mut state =
+        ListState::default().with_selected(Some(app.search_selected.saturating_sub(first)));
+    f.render_stateful_widget(list, area, &mut state);
+
+    // Record where each visible result landed
+    let mut rows = Vec::new();
+    let mut y = list_area.y;
+    for (idx, &height) in heights.iter().enumerate().skip(state.offset()) {
+        if y >= list_area.bottom() {
+            break;
+        }
+        rows.push((
+            first + idx,
+            Rect::new(list_area.x, y, list_area.width, height).intersection(list_area),
+        ));
+        y = y.saturating_add(height);
+    }
+    SearchLayout { rows }
+}
+
+fn match_style() -> Style {
+    Style::default()
+        .fg(COLOR_SEARCH_MATCH)
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+}
+
+/// Second line of a result: the first other field the query matched in, cut
+/// down to the width around the match.
+fn snippet_line(issue: &Issue, matches: &[FieldMatch], width: usize) -> Option<Line<'static>> {
+    let field_match = matches.iter().find(|m| m.field != SearchField::Title)?;
+    let (name, text) = match field_match.field {
+        SearchField::Description => ("description", issue.description.clone()?),
+        SearchField::Labels => ("labels", issue.labels.join(", ")),
+        SearchField::Assignee => ("assignee", issue.assignee.clone()?),
+        SearchField::Title => return None,
+    };
+
+    // Newlines and tabs would break the line; one space each keeps the indices
+    let chars: Vec<char> = text
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect();
+    let prefix = format!("    {}: ", name);
+    let room = width.saturating_sub(prefix.chars().count()).max(1);
+    let first = field_match.indices.first().copied().unwrap_or(0);
+    let start = first
+        .saturating_sub(SNIPPET_CONTEXT)
+        .min(chars.len().saturating_sub(room));
+    let end = (start + room).min(chars.len());
+
+    let mut snippet: Vec<char> = chars[start..end].to_vec();
+    if start > 0 {
+        snippet[0] = '…';
+    }
+    if end < chars.len() {
+        if let Some(last) = snippet.last_mut() {
+            *last = '…';
+        }
+    }
+    let indices: Vec<usize> = field_match
+        .indices
+        .iter()
+        .filter(|&&i| i >= start && i < end)
+        .map(|i| i - start)
+        .collect();
+
+    let dim = Style::default().fg(COLOR_SECONDARY_TEXT);
+    let mut spans = vec![Span::styled(prefix, dim)];
+    spans.extend(highlight_matches(
+        &snippet.into_iter().collect::<String>(),
+        &indices,
+        dim,
+        match_style(),
+    ));
+    Some(Line::from(spans))
 }
 
-fn render_search_footer(f: &mut Frame, area: Rect) {
-    let help = "[Type] Search  [Up/Down] Navigate  [Enter] View  [Esc] Back  [Ctrl+U] Clear";
-    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+fn render_search_footer(f: &mut Frame, area: Rect, app: &App) {
+    let paragraph = match app.status_message {
+        Some(ref msg) => Paragraph::new(msg.as_str()).style(Style::default().fg(COLOR_BLOCKED)),
+        None => {
+            Paragraph::new(keymap::footer(View::Search)).style(Style::default().fg(COLOR_HELP_TEXT))
+        }
+    };
     f.render_widget(paragraph, area);
 }
@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use super::app::{App, View};
+use super::keymap;
+use super::theme::*;
+use crate::beads::models::parse_timestamp;
+use crate::beads::{Issue, Status};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableColumn {
+    Id,
+    Title,
+    Priority,
+    Status,
+    Type,
+    Assignee,
+    Labels,
+    Created,
+    Updated,
+}
+
+impl TableColumn {
+    pub const ALL: [TableColumn; 9] = [
+        TableColumn::Id,
+        TableColumn::Title,
+        TableColumn::Priority,
+        TableColumn::Status,
+        TableColumn::Type,
+        TableColumn::Assignee,
+        TableColumn::Labels,
+        TableColumn::Created,
+        TableColumn::Updated,
+    ];
+
+    /// Looks a column up by its lowercase name, as used in the config.
+    pub fn from_name(name: &str) -> Option<TableColumn> {
+        TableColumn::ALL
+            .into_iter()
+            .find(|c| c.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TableColumn::Id => "id",
+            TableColumn::Title => "title",
+            TableColumn::Priority => "priority",
+            TableColumn::Status => "status",
+            TableColumn::Type => "type",
+            TableColumn::Assignee => "assignee",
+            TableColumn::Labels => "labels",
+            TableColumn::Created => "created",
+            TableColumn::Updated => "updated",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            TableColumn::Id => "ID",
+            TableColumn::Title => "Title",
+            TableColumn::Priority => "Pri",
+            TableColumn::Status => "Status",
+            TableColumn::Type => "Type",
+            TableColumn::Assignee => "Assignee",
+            TableColumn::Labels => "Labels",
+            TableColumn::Created => "Created",
+            TableColumn::Updated => "Updated",
+        }
+    }
+
+    fn width(&self) -> Constraint {
+        match self {
+            TableColumn::Id => Constraint::Length(12),
+            TableColumn::Title => Constraint::Min(20),
+            TableColumn::Priority => Constraint::Length(5),
+            TableColumn::Status => Constraint::Length(11),
+            TableColumn::Type => Constraint::Length(8),
+            TableColumn::Assignee => Constraint::Length(12),
+            TableColumn::Labels => Constraint::Length(16),
+            TableColumn::Created | TableColumn::Updated => Constraint::Length(10),
+        }
+    }
+
+    /// Orders two issues by this column, ascending.
+    pub fn compare(&self, a: &Issue, b: &Issue) -> Ordering {
+        match self {
+            TableColumn::Id => a.id.cmp(&b.id),
+            TableColumn::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            TableColumn::Priority => a.priority.cmp(&b.priority),
+            TableColumn::Status => status_rank(a.status).cmp(&status_rank(b.status)),
+            TableColumn::Type => a.issue_type.to_string().cmp(&b.issue_type.to_string()),
+            // Unassigned sorts last
+            TableColumn::Assignee => match (&a.assignee, &b.assignee) {
+                (Some(x), Some(y)) => x.cmp(y),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            TableColumn::Labels => a.labels.join(",").cmp(&b.labels.join(",")),
+            TableColumn::Created => {
+                parse_timestamp(&a.created_at).cmp(&parse_timestamp(&b.created_at))
+            }
+            TableColumn::Updated => {
+                parse_timestamp(&a.updated_at).cmp(&parse_timestamp(&b.updated_at))
+            }
+        }
+    }
+
+    fn cell<'a>(&self, issue: &'a Issue) -> Cell<'a> {
+        match self {
+            TableColumn::Id => Cell::from(issue.id.as_str()).style(Style::default().fg(COLOR_SECONDARY_TEXT)),
+            TableColumn::Title => Cell::from(issue.title.as_str()),
+            TableColumn::Priority => Cell::from(issue.priority.label()).style(
+                Style::default()
+                    .fg(priority_color(issue.priority.0))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            TableColumn::Status => Cell::from(issue.status.to_string())
+                .style(Style::default().fg(status_color(issue.status))),
+            TableColumn::Type => Cell::from(issue.issue_type.to_string()),
+            TableColumn::Assignee => Cell::from(issue.assignee.as_deref().unwrap_or("")),
+            TableColumn::Labels => Cell::from(issue.labels.join(", "))
+                .style(Style::default().fg(COLOR_SECONDARY_TEXT)),
+            TableColumn::Created => date_cell(&issue.created_at),
+            TableColumn::Updated => date_cell(&issue.updated_at),
+        }
+    }
+}
+
+/// Workflow order, so sorting by status reads left to right like the board.
+fn status_rank(status: Status) -> u8 {
+    match status {
+        Status::Open => 0,
+        Status::Blocked => 1,
+        Status::Deferred => 2,
+        Status::InProgress => 3,
+        Status::Closed => 4,
+        Status::Unknown => 5,
+    }
+}
+
+fn date_cell(timestamp: &str) -> Cell<'static> {
+    let date = parse_timestamp(timestamp)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_default();
+    Cell::from(date).style(Style::default().fg(COLOR_SECONDARY_TEXT))
+}
+
+pub fn render_table(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Table
+            Constraint::Length(1), // Column toggles
+            Constraint::Length(1), // Footer
+        ])
+        .split(f.area());
+
+    render_issue_table(f, chunks[0], app);
+    render_column_toggles(f, chunks[1], app);
+    render_table_footer(f, chunks[2]);
+}
+
+fn render_issue_table(f: &mut Frame, area: Rect, app: &App) {
+    let issues = app.table_issues();
+
+    let header = Row::new(app.table_columns.iter().enumerate().map(|(idx, column)| {
+        let mut title = column.title().to_string();
+        if *column == app.table_sort {
+            title.push_str(if app.table_sort_desc { " ▼" } else { " ▲" });
+        }
+        let mut style = Style::default().fg(COLOR_HEADER).add_modifier(Modifier::BOLD);
+        if idx == app.table_cursor {
+            style = style.bg(COLOR_SELECTED_BG).add_modifier(Modifier::UNDERLINED);
+        }
+        Cell::from(title).style(style)
+    }));
+
+    let rows = issues
+        .iter()
+        .map(|issue| Row::new(app.table_columns.iter().map(|column| column.cell(issue))));
+
+    let widths: Vec<Constraint> = app.table_columns.iter().map(TableColumn::width).collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Issues ({}) ", issues.len()))
+        .style(Style::default());
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .column_spacing(1)
+        .row_highlight_style(
+            Style::default()
+                .bg(COLOR_SELECTED_BG)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut state = TableState::default().with_selected(Some(app.table_selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+/// Numbered list of all columns; hidden ones are dimmed.
+fn render_column_toggles(f: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![Span::styled("Columns: ", Style::default().fg(COLOR_HELP_TEXT))];
+    for (idx, column) in TableColumn::ALL.iter().enumerate() {
+        let style = if app.table_columns.contains(column) {
+            Style::default().fg(COLOR_HEADER)
+        } else {
+            Style::default()
+                .fg(COLOR_SEPARATOR)
+                .add_modifier(Modifier::CROSSED_OUT)
+        };
+        spans.push(Span::styled(format!("{}:{} ", idx + 1, column.title()), style));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_table_footer(f: &mut Frame, area: Rect) {
+    let help = keymap::footer(View::Table);
+    let paragraph = Paragraph::new(help).style(Style::default().fg(COLOR_HELP_TEXT));
+    f.render_widget(paragraph, area);
+}
@@ -1,6 +1,8 @@
 use ratatui::style::Color;
 use ratatui::style::palette::material::*;
 
+use crate::beads::Status;
+
 // Column Status Colors
 pub const COLOR_OPEN: Color = BLUE.c400;           // Bright, inviting blue
 pub const COLOR_IN_PROGRESS: Color = AMBER.c500;   // Warm, active amber
@@ -16,19 +18,20 @@ pub const COLOR_P4: Color = BLUE_GRAY.c300;       // Very low blue-gray
 // Semantic Colors
 pub const COLOR_BLOCKED: Color = RED.c700;         // Darker red for blocked
 pub const COLOR_BLOCKS: Color = ORANGE.c500;      // Orange for blocking
+pub const COLOR_WARNING: Color = PINK.c400;        // WIP limit exceeded
 
 // UI Element Colors
 pub const COLOR_HEADER: Color = CYAN.c300;         // Header branding
 pub const COLOR_HEADER_BG: Color = BLUE_GRAY.c900; // Header background
 pub const COLOR_SELECTED_BG: Color = BLUE_GRAY.c800; // Selection highlight
 pub const COLOR_BORDER: Color = BLUE_GRAY.c600;   // Inactive borders
-#[allow(dead_code)]
-pub const COLOR_BORDER_ACTIVE: Color = CYAN.c400; // Active borders (reserved for future use)
+pub const COLOR_BORDER_ACTIVE: Color = CYAN.c400; // Active borders (drop targets)
 
 // Text Colors
 pub const COLOR_SECONDARY_TEXT: Color = GRAY.c500; // Issue IDs, timestamps
 pub const COLOR_HELP_TEXT: Color = BLUE_GRAY.c400; // Footer help
 pub const COLOR_SEPARATOR: Color = BLUE_GRAY.c700; // Visual separators
+pub const COLOR_LINK: Color = LIGHT_BLUE.c300;     // Issue ID mentions
 
 // Scrollbar Colors
 pub const COLOR_SCROLLBAR_THUMB: Color = BLUE_GRAY.c400;
@@ -48,3 +51,13 @@ pub fn priority_color(priority: u8) -> Color {
         _ => COLOR_P4,
     }
 }
+
+// Helper function for status colors, matching the board columns
+pub fn status_color(status: Status) -> Color {
+    match status {
+        Status::InProgress => COLOR_IN_PROGRESS,
+        Status::Closed => COLOR_DONE,
+        Status::Blocked => COLOR_BLOCKED,
+        Status::Open | Status::Deferred | Status::Unknown => COLOR_OPEN,
+    }
+}